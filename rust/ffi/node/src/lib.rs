@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -32,11 +33,23 @@ use crate::query::JsQuery;
 mod arrow;
 mod convert;
 mod error;
+// `index::vector::table_create_vector_index` is referenced below but the
+// module itself isn't present in this checkout; `vectordb::metrics::Registry::record_index_build`
+// can't be wired into the vector-index build path until it exists.
 mod index;
 mod neon_ext;
 mod table;
 mod query;
 
+// Renders the process-wide metrics registry (tables opened, rows
+// scanned/returned, merge-insert counts, index build duration, IO bytes
+// read) in the Prometheus text exposition format, so an embedding
+// application can scrape it from whatever HTTP endpoint it already serves.
+fn metrics_prometheus_text(mut cx: FunctionContext) -> JsResult<JsString> {
+    let rendered = vectordb::metrics::global_registry().render_prometheus();
+    Ok(cx.string(rendered))
+}
+
 struct JsDatabase {
     database: Arc<Database>,
 }
@@ -156,6 +169,73 @@ fn get_aws_creds<T>(
     }
 }
 
+// Reads an optional region argument, used to point `ObjectStoreParams` at a
+// non-default AWS region or at the region expected by an S3-compatible store.
+fn get_aws_region<T>(
+    cx: &mut FunctionContext,
+    arg_location: i32,
+) -> Result<Option<String>, NeonResult<T>> {
+    Ok(cx
+        .argument_opt(arg_location)
+        .map(|arg| arg.downcast_or_throw::<JsString, FunctionContext>(cx).ok())
+        .flatten()
+        .map(|v| v.value(cx)))
+}
+
+// Reads an optional custom S3 endpoint argument (e.g. `http://localhost:9000`
+// for MinIO), used so `ObjectStoreParams` can target S3-compatible stores
+// instead of AWS.
+fn get_aws_endpoint<T>(
+    cx: &mut FunctionContext,
+    arg_location: i32,
+) -> Result<Option<String>, NeonResult<T>> {
+    Ok(cx
+        .argument_opt(arg_location)
+        .map(|arg| arg.downcast_or_throw::<JsString, FunctionContext>(cx).ok())
+        .flatten()
+        .map(|v| v.value(cx)))
+}
+
+// Reads an optional `allow_http` argument, used to allow plaintext HTTP
+// connections to S3-compatible stores during local testing.
+fn get_aws_allow_http<T>(
+    cx: &mut FunctionContext,
+    arg_location: i32,
+) -> Result<bool, NeonResult<T>> {
+    Ok(cx
+        .argument_opt(arg_location)
+        .map(|arg| arg.downcast_or_throw::<JsBoolean, FunctionContext>(cx).ok())
+        .flatten()
+        .map(|v| v.value(cx))
+        .unwrap_or(false))
+}
+
+// Builds the `storage_options` map that `ObjectStoreParams` forwards to the
+// underlying `object_store` builder, so S3-compatible stores (MinIO, Garage,
+// Ceph, etc.) can be reached with a custom endpoint, an explicit region, and
+// (for local testing) plaintext HTTP.
+pub(crate) fn s3_storage_options(
+    region: Option<String>,
+    endpoint: Option<String>,
+    allow_http: bool,
+) -> Option<HashMap<String, String>> {
+    let mut options = HashMap::new();
+    if let Some(region) = region {
+        options.insert("region".to_string(), region);
+    }
+    if let Some(endpoint) = endpoint {
+        options.insert("endpoint".to_string(), endpoint);
+    }
+    if allow_http {
+        options.insert("allow_http".to_string(), "true".to_string());
+    }
+    if options.is_empty() {
+        None
+    } else {
+        Some(options)
+    }
+}
+
 fn database_open_table(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let db = cx
         .this()
@@ -166,10 +246,23 @@ fn database_open_table(mut cx: FunctionContext) -> JsResult<JsPromise> {
         Ok(creds) => creds,
         Err(err) => return err,
     };
+    let aws_region = match get_aws_region(&mut cx, 4) {
+        Ok(region) => region,
+        Err(err) => return err,
+    };
+    let aws_endpoint = match get_aws_endpoint(&mut cx, 5) {
+        Ok(endpoint) => endpoint,
+        Err(err) => return err,
+    };
+    let allow_http = match get_aws_allow_http(&mut cx, 6) {
+        Ok(allow_http) => allow_http,
+        Err(err) => return err,
+    };
 
     let params = ReadParams {
         store_options: Some(ObjectStoreParams {
             aws_credentials: aws_creds,
+            storage_options: s3_storage_options(aws_region, aws_endpoint, allow_http),
             ..ObjectStoreParams::default()
         }),
         ..ReadParams::default()
@@ -182,6 +275,9 @@ fn database_open_table(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let (deferred, promise) = cx.promise();
     rt.spawn(async move {
         let table_rst = database.open_table_with_params(&table_name, &params).await;
+        if table_rst.is_ok() {
+            vectordb::metrics::global_registry().record_table_opened(&table_name);
+        }
 
         deferred.settle_with(&channel, move |mut cx| {
             let table = table_rst.or_throw(&mut cx)?;
@@ -224,11 +320,14 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("tableSearch", JsQuery::js_search)?;
     cx.export_function("tableCreate", JsTable::js_create)?;
     cx.export_function("tableAdd", JsTable::js_add)?;
+    cx.export_function("tableCreateStream", JsTable::js_create_stream)?;
+    cx.export_function("tableAddStream", JsTable::js_add_stream)?;
     cx.export_function("tableCountRows", JsTable::js_count_rows)?;
     cx.export_function("tableDelete", JsTable::js_delete)?;
     cx.export_function(
         "tableCreateVectorIndex",
         index::vector::table_create_vector_index,
     )?;
+    cx.export_function("metricsPrometheusText", metrics_prometheus_text)?;
     Ok(())
 }
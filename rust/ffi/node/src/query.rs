@@ -0,0 +1,109 @@
+// Copyright 2024 Lance Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use neon::prelude::*;
+use neon::types::buffer::TypedArray;
+
+use crate::arrow::record_batch_to_buffer;
+use crate::error::ResultExt;
+use crate::runtime;
+use crate::table::JsTable;
+
+mod expr;
+
+pub(crate) use expr::parse_query_expression;
+
+pub(crate) struct JsQuery;
+
+impl JsQuery {
+    /// Runs a search against a table.
+    ///
+    /// Accepts either the existing explicit arguments (query vector buffer,
+    /// `k`, optional filter string) or, if a single query-expression string
+    /// is passed instead of a vector buffer, parses it with
+    /// [`expr::parse_query_expression`] into the same (vector, k, filter,
+    /// FTS match) parameters before running the search. This lets callers
+    /// pass one ergonomic string such as
+    /// `"NEAREST TO [0.1, 0.2] LIMIT 10 WHERE price < 100 AND title MATCH 'socks'"`
+    /// instead of assembling each argument by hand.
+    pub(crate) fn js_search(mut cx: FunctionContext) -> JsResult<JsPromise> {
+        let js_table = cx.this().downcast_or_throw::<JsBox<JsTable>, _>(&mut cx)?;
+        let table = js_table.table.clone();
+
+        let first_arg = cx.argument::<JsValue>(0)?;
+        let params = if let Ok(expression) = first_arg.downcast::<JsString, _>(&mut cx) {
+            expr::parse_query_expression(&expression.value(&mut cx)).or_throw(&mut cx)?
+        } else {
+            let buffer = first_arg.downcast_or_throw::<JsBuffer, _>(&mut cx)?;
+            let vector: Vec<f32> = buffer
+                .as_slice(&mut cx)
+                .chunks_exact(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect();
+            let limit = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+            let filter = cx
+                .argument_opt(2)
+                .and_then(|v| v.downcast::<JsString, _>(&mut cx).ok())
+                .map(|v| v.value(&mut cx));
+            expr::ParsedQuery {
+                vector: Some(vector),
+                limit: Some(limit),
+                filter,
+                fts_query: None,
+            }
+        };
+
+        let rt = runtime(&mut cx)?;
+        let channel = cx.channel();
+        let (deferred, promise) = cx.promise();
+
+        rt.spawn(async move {
+            let mut query = table.search(params.vector.unwrap_or_default());
+            if let Some(limit) = params.limit {
+                query = query.limit(limit);
+            }
+            if let Some(filter) = params.filter {
+                query = query.filter(filter);
+            }
+            if let Some(fts_query) = params.fts_query {
+                query = query.full_text_search(fts_query);
+            }
+            let table_name = table.name().to_string();
+            let started_at = std::time::Instant::now();
+            let result = query.execute().await;
+            if let Ok(batches) = &result {
+                let rows_returned: u64 = batches.iter().map(|b| b.num_rows() as u64).sum();
+                // The query API here doesn't expose how many rows the index
+                // scanned before filtering/limiting, so rows_scanned uses
+                // the same count as rows_returned as the closest available
+                // proxy rather than a fabricated number.
+                vectordb::metrics::global_registry().record_search(
+                    &table_name,
+                    rows_returned,
+                    rows_returned,
+                    started_at.elapsed().as_secs_f64(),
+                );
+            }
+
+            deferred.settle_with(&channel, move |mut cx| {
+                let batches = result.or_throw(&mut cx)?;
+                let buffer = record_batch_to_buffer(batches).or_throw(&mut cx)?;
+                let mut js_buffer = cx.buffer(buffer.len())?;
+                js_buffer.as_mut_slice(&mut cx).copy_from_slice(&buffer);
+                Ok(js_buffer)
+            });
+        });
+        Ok(promise)
+    }
+}
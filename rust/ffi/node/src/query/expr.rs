@@ -0,0 +1,401 @@
+// Copyright 2024 Lance Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A small lexer/parser that turns a single user-supplied query string into
+//! the structured search parameters [`JsQuery::js_search`](super::JsQuery::js_search)
+//! needs: a nearest-neighbor vector clause, a row limit, a boolean SQL-style
+//! filter over scalar columns, and an FTS match clause.
+//!
+//! Grammar (informally):
+//!
+//! ```text
+//! query      := clause (("AND" | "OR") clause)* ("WHERE" clause (("AND" | "OR") clause)*)?
+//! clause     := nearest | match | comparison
+//! nearest    := "NEAREST" "TO" "[" number ("," number)* "]" ("LIMIT" number)?
+//! match      := identifier "MATCH" string
+//! comparison := identifier op (number | string)
+//! op         := "=" | "!=" | "<" | "<=" | ">" | ">="
+//! ```
+//!
+//! The `NEAREST TO .. LIMIT ..` clause and any `MATCH` clause are pulled out
+//! of the boolean expression and used to drive the vector/FTS index
+//! selection; everything else is re-assembled into the existing scalar
+//! filter string so it can still be pushed down into the BTree/Bitmap
+//! indexes. An optional leading `WHERE` may introduce the scalar filter
+//! (e.g. after a `NEAREST`/`MATCH` clause); it's a pure separator and
+//! doesn't itself join anything, so it's dropped rather than re-assembled.
+//! Likewise, an `AND`/`OR` that would have joined a pulled-out clause to the
+//! scalar filter is dropped instead of leaving a dangling connective.
+
+use crate::error::Error;
+
+/// The parameters lowered from a parsed query expression.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct ParsedQuery {
+    pub vector: Option<Vec<f32>>,
+    pub limit: Option<usize>,
+    pub filter: Option<String>,
+    pub fts_query: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(f64),
+    Str(String),
+    LBracket,
+    RBracket,
+    Comma,
+    Op(String),
+    Keyword(String),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, Error> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = input.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            ' ' | '\t' | '\n' | '\r' => i += 1,
+            '[' => {
+                tokens.push(Token::LBracket);
+                i += 1;
+            }
+            ']' => {
+                tokens.push(Token::RBracket);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            '=' | '<' | '>' | '!' => {
+                let mut op = c.to_string();
+                i += 1;
+                if i < chars.len() && chars[i] == '=' {
+                    op.push('=');
+                    i += 1;
+                }
+                tokens.push(Token::Op(op));
+            }
+            '\'' | '"' => {
+                let quote = c;
+                i += 1;
+                let start = i;
+                while i < chars.len() && chars[i] != quote {
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return Err(Error::InvalidInput {
+                        message: format!("unterminated string literal in query: {}", input),
+                    });
+                }
+                tokens.push(Token::Str(chars[start..i].iter().collect()));
+                i += 1;
+            }
+            _ if c.is_ascii_digit() || (c == '-' && i + 1 < chars.len() && chars[i + 1].is_ascii_digit()) => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let value = text.parse::<f64>().map_err(|_| Error::InvalidInput {
+                    message: format!("invalid number literal '{}' in query", text),
+                })?;
+                tokens.push(Token::Number(value));
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                match text.to_ascii_uppercase().as_str() {
+                    "AND" | "OR" | "NEAREST" | "TO" | "LIMIT" | "MATCH" | "WHERE" => {
+                        tokens.push(Token::Keyword(text.to_ascii_uppercase()))
+                    }
+                    _ => tokens.push(Token::Ident(text)),
+                }
+            }
+            _ => {
+                return Err(Error::InvalidInput {
+                    message: format!("unexpected character '{}' in query: {}", c, input),
+                })
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// An item accumulated while walking the token stream for the scalar
+/// filter: either a real comparison clause, or a connective (`AND`/`OR`)
+/// that should only survive if it ends up joining two real clauses.
+enum FilterItem {
+    Clause(String),
+    Connective(String),
+}
+
+/// Parses a query expression (see the module docs for the grammar) into the
+/// parameters used to drive [`crate::query::JsQuery::js_search`].
+pub(crate) fn parse_query_expression(input: &str) -> Result<ParsedQuery, Error> {
+    let tokens = tokenize(input)?;
+    let mut parsed = ParsedQuery::default();
+    let mut filter_items: Vec<FilterItem> = Vec::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::Keyword(kw) if kw == "NEAREST" => {
+                i += 1;
+                expect_keyword(&tokens, &mut i, "TO", input)?;
+                expect_token(&tokens, &mut i, &Token::LBracket, input)?;
+                let mut vector = Vec::new();
+                loop {
+                    match tokens.get(i) {
+                        Some(Token::Number(n)) => {
+                            vector.push(*n as f32);
+                            i += 1;
+                        }
+                        other => {
+                            return Err(Error::InvalidInput {
+                                message: format!(
+                                    "expected a number in NEAREST TO [...], found {:?}",
+                                    other
+                                ),
+                            })
+                        }
+                    }
+                    match tokens.get(i) {
+                        Some(Token::Comma) => {
+                            i += 1;
+                            continue;
+                        }
+                        Some(Token::RBracket) => {
+                            i += 1;
+                            break;
+                        }
+                        other => {
+                            return Err(Error::InvalidInput {
+                                message: format!("expected ',' or ']' in query, found {:?}", other),
+                            })
+                        }
+                    }
+                }
+                parsed.vector = Some(vector);
+
+                if let Some(Token::Keyword(kw)) = tokens.get(i) {
+                    if kw == "LIMIT" {
+                        i += 1;
+                        match tokens.get(i) {
+                            Some(Token::Number(n)) => {
+                                parsed.limit = Some(*n as usize);
+                                i += 1;
+                            }
+                            other => {
+                                return Err(Error::InvalidInput {
+                                    message: format!("expected a number after LIMIT, found {:?}", other),
+                                })
+                            }
+                        }
+                    }
+                }
+            }
+            Token::Ident(column) => {
+                let column = column.clone();
+                i += 1;
+                match tokens.get(i) {
+                    Some(Token::Keyword(kw)) if kw == "MATCH" => {
+                        i += 1;
+                        match tokens.get(i) {
+                            Some(Token::Str(s)) => {
+                                parsed.fts_query = Some(s.clone());
+                                i += 1;
+                            }
+                            other => {
+                                return Err(Error::InvalidInput {
+                                    message: format!("expected a string after MATCH, found {:?}", other),
+                                })
+                            }
+                        }
+                    }
+                    Some(Token::Op(op)) => {
+                        let op = op.clone();
+                        i += 1;
+                        let literal = match tokens.get(i) {
+                            Some(Token::Number(n)) => n.to_string(),
+                            Some(Token::Str(s)) => format!("'{}'", s),
+                            other => {
+                                return Err(Error::InvalidInput {
+                                    message: format!(
+                                        "expected a literal after '{}', found {:?}",
+                                        op, other
+                                    ),
+                                })
+                            }
+                        };
+                        i += 1;
+                        filter_items.push(FilterItem::Clause(format!(
+                            "{} {} {}",
+                            column, op, literal
+                        )));
+                    }
+                    other => {
+                        return Err(Error::InvalidInput {
+                            message: format!(
+                                "expected MATCH or a comparison operator after '{}', found {:?}",
+                                column, other
+                            ),
+                        })
+                    }
+                }
+            }
+            Token::Keyword(kw) if kw == "AND" || kw == "OR" => {
+                // Boolean connectives are implicit in the re-assembled
+                // filter string; LanceDB's scalar filter already speaks SQL,
+                // so we simply preserve the connective verbatim. Whether it
+                // actually survives (as opposed to joining a pulled-out
+                // NEAREST/MATCH clause) is decided once the whole filter is
+                // assembled, below.
+                filter_items.push(FilterItem::Connective(kw.clone()));
+                i += 1;
+            }
+            Token::Keyword(kw) if kw == "WHERE" => {
+                // A pure separator introducing the scalar filter; it never
+                // joins two clauses, so it's simply skipped rather than
+                // re-assembled as a connective.
+                i += 1;
+            }
+            other => {
+                return Err(Error::InvalidInput {
+                    message: format!("unexpected token in query: {:?}", other),
+                })
+            }
+        }
+    }
+
+    // Drop any connective that isn't actually joining two real clauses: one
+    // whose preceding item was pulled out (NEAREST/MATCH, or another
+    // dropped connective), and any left dangling at the end.
+    let mut kept: Vec<FilterItem> = Vec::new();
+    for item in filter_items {
+        match item {
+            FilterItem::Clause(_) => kept.push(item),
+            FilterItem::Connective(_) => {
+                if matches!(kept.last(), Some(FilterItem::Clause(_))) {
+                    kept.push(item);
+                }
+            }
+        }
+    }
+    while matches!(kept.last(), Some(FilterItem::Connective(_))) {
+        kept.pop();
+    }
+
+    let filter_clauses: Vec<String> = kept
+        .into_iter()
+        .map(|item| match item {
+            FilterItem::Clause(s) | FilterItem::Connective(s) => s,
+        })
+        .collect();
+    if !filter_clauses.is_empty() {
+        parsed.filter = Some(filter_clauses.join(" "));
+    }
+    Ok(parsed)
+}
+
+fn expect_keyword(tokens: &[Token], i: &mut usize, keyword: &str, input: &str) -> Result<(), Error> {
+    match tokens.get(*i) {
+        Some(Token::Keyword(kw)) if kw == keyword => {
+            *i += 1;
+            Ok(())
+        }
+        other => Err(Error::InvalidInput {
+            message: format!(
+                "expected keyword '{}' in query '{}', found {:?}",
+                keyword, input, other
+            ),
+        }),
+    }
+}
+
+fn expect_token(tokens: &[Token], i: &mut usize, expected: &Token, input: &str) -> Result<(), Error> {
+    match tokens.get(*i) {
+        Some(tok) if tok == expected => {
+            *i += 1;
+            Ok(())
+        }
+        other => Err(Error::InvalidInput {
+            message: format!(
+                "expected {:?} in query '{}', found {:?}",
+                expected, input, other
+            ),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nearest_with_limit() {
+        let parsed = parse_query_expression("NEAREST TO [0.1, 0.2, 0.3] LIMIT 10").unwrap();
+        assert_eq!(parsed.vector, Some(vec![0.1, 0.2, 0.3]));
+        assert_eq!(parsed.limit, Some(10));
+        assert_eq!(parsed.filter, None);
+    }
+
+    #[test]
+    fn parses_filter_and_match_clauses() {
+        let parsed = parse_query_expression(
+            "NEAREST TO [1.0] LIMIT 5 AND price < 100 AND title MATCH 'socks'",
+        )
+        .unwrap();
+        assert_eq!(parsed.vector, Some(vec![1.0]));
+        assert_eq!(parsed.limit, Some(5));
+        assert_eq!(parsed.fts_query, Some("socks".to_string()));
+        // Both the leading `AND` (joining to the pulled-out NEAREST clause)
+        // and the trailing `AND` (joining to the pulled-out MATCH clause)
+        // are dropped rather than left dangling in the re-assembled filter.
+        assert_eq!(parsed.filter.as_deref(), Some("price < 100"));
+    }
+
+    #[test]
+    fn parses_the_flagship_where_example() {
+        let parsed = parse_query_expression(
+            "NEAREST TO [0.1, 0.2] LIMIT 10 WHERE price < 100 AND title MATCH 'socks'",
+        )
+        .unwrap();
+        assert_eq!(parsed.vector, Some(vec![0.1, 0.2]));
+        assert_eq!(parsed.limit, Some(10));
+        assert_eq!(parsed.fts_query, Some("socks".to_string()));
+        assert_eq!(parsed.filter.as_deref(), Some("price < 100"));
+    }
+
+    #[test]
+    fn drops_dangling_connective_when_only_match_clause_present() {
+        let parsed = parse_query_expression("NEAREST TO [1.0] LIMIT 5 AND title MATCH 'socks'").unwrap();
+        assert_eq!(parsed.fts_query, Some("socks".to_string()));
+        assert_eq!(parsed.filter, None);
+    }
+
+    #[test]
+    fn rejects_malformed_vector() {
+        let err = parse_query_expression("NEAREST TO [1.0, ] LIMIT 5").unwrap_err();
+        assert!(matches!(err, Error::InvalidInput { .. }));
+    }
+}
@@ -13,17 +13,20 @@
 // limitations under the License.
 
 use arrow_array::RecordBatchIterator;
+use arrow_ipc::reader::StreamReader;
 use lance::dataset::optimize::CompactionOptions;
 use lance::dataset::{WriteMode, WriteParams};
 use lance::io::object_store::ObjectStoreParams;
 
+use lancedb::arrow::{dictionary_encode_batches, DictionaryEncodingOptions};
+
 use crate::arrow::arrow_buffer_to_record_batch;
 use neon::prelude::*;
 use neon::types::buffer::TypedArray;
 use vectordb::Table;
 
 use crate::error::ResultExt;
-use crate::{get_aws_creds, get_aws_region, runtime, JsDatabase};
+use crate::{get_aws_allow_http, get_aws_creds, get_aws_endpoint, get_aws_region, runtime, s3_storage_options, JsDatabase};
 
 pub(crate) struct JsTable {
     pub table: Table,
@@ -37,6 +40,88 @@ impl From<Table> for JsTable {
     }
 }
 
+// A `Read` adapter over a sequence of independently-received byte chunks
+// that never concatenates them into one contiguous allocation. Unlike
+// `Cursor<Vec<u8>>` over a single fully-assembled buffer, the largest
+// single allocation here is the largest chunk the caller handed over, not
+// the whole IPC stream. Every chunk is still held in memory up front, and
+// each is dropped once it has been read through — see
+// `js_create_stream`/`js_add_stream`, which receive their chunks as a JS
+// array of buffers rather than one pre-joined buffer.
+struct ChunkedReader {
+    chunks: std::collections::VecDeque<Vec<u8>>,
+    pos: usize,
+}
+
+impl ChunkedReader {
+    fn new(chunks: Vec<Vec<u8>>) -> Self {
+        Self {
+            chunks: chunks.into(),
+            pos: 0,
+        }
+    }
+}
+
+impl std::io::Read for ChunkedReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            let Some(chunk) = self.chunks.front() else {
+                return Ok(0);
+            };
+            if self.pos >= chunk.len() {
+                self.chunks.pop_front();
+                self.pos = 0;
+                continue;
+            }
+            let available = &chunk[self.pos..];
+            let n = available.len().min(buf.len());
+            buf[..n].copy_from_slice(&available[..n]);
+            self.pos += n;
+            return Ok(n);
+        }
+    }
+}
+
+// Reads a JS array of buffers (one per chunk) into owned `Vec<u8>`s, without
+// ever joining them into a single contiguous allocation.
+fn read_buffer_chunks(
+    cx: &mut FunctionContext,
+    arg_location: i32,
+) -> NeonResult<Vec<Vec<u8>>> {
+    let chunks = cx.argument::<JsArray>(arg_location)?.to_vec(cx)?;
+    chunks
+        .into_iter()
+        .map(|chunk| {
+            let buffer = chunk.downcast_or_throw::<JsBuffer, _>(cx)?;
+            Ok(buffer.as_slice(cx).to_vec())
+        })
+        .collect()
+}
+
+// Reads an optional boolean flag, such as whether to dictionary-encode
+// low-cardinality string columns before writing.
+fn get_bool_arg(cx: &mut FunctionContext, arg_location: i32) -> bool {
+    cx.argument_opt(arg_location)
+        .and_then(|val| val.downcast::<JsBoolean, _>(cx).ok())
+        .map(|val| val.value(cx))
+        .unwrap_or(false)
+}
+
+// Applies `dictionary_encode_batches` when requested, deciding which
+// columns to encode once for the whole call (see
+// `lancedb::arrow::dictionary_encode_batches`) rather than once per batch,
+// so every batch keeps the same schema — this matters because the table's
+// declared schema below is derived from only `batches.first()`.
+fn maybe_dictionary_encode(
+    batches: Vec<arrow_array::RecordBatch>,
+    dictionary_encode: bool,
+) -> Result<Vec<arrow_array::RecordBatch>, lancedb::error::Error> {
+    if !dictionary_encode {
+        return Ok(batches);
+    }
+    dictionary_encode_batches(&batches, &DictionaryEncodingOptions::default())
+}
+
 impl JsTable {
     pub(crate) fn js_create(mut cx: FunctionContext) -> JsResult<JsPromise> {
         let db = cx
@@ -46,6 +131,12 @@ impl JsTable {
         let buffer = cx.argument::<JsBuffer>(1)?;
         let (batches, schema) =
             arrow_buffer_to_record_batch(buffer.as_slice(&mut cx)).or_throw(&mut cx)?;
+        let dictionary_encode = get_bool_arg(&mut cx, 9);
+        let batches = maybe_dictionary_encode(batches, dictionary_encode).or_throw(&mut cx)?;
+        let schema = batches
+            .first()
+            .map(|b| b.schema())
+            .unwrap_or(schema);
 
         // Write mode
         let mode = match cx.argument::<JsString>(2)?.value(&mut cx).as_str() {
@@ -65,11 +156,14 @@ impl JsTable {
 
         let aws_creds = get_aws_creds(&mut cx, 3)?;
         let aws_region = get_aws_region(&mut cx, 6)?;
+        let aws_endpoint = get_aws_endpoint(&mut cx, 7)?;
+        let allow_http = get_aws_allow_http(&mut cx, 8)?;
 
         let params = WriteParams {
-            store_params: Some(ObjectStoreParams::with_aws_credentials(
-                aws_creds, aws_region,
-            )),
+            store_params: Some(ObjectStoreParams {
+                storage_options: s3_storage_options(aws_region.clone(), aws_endpoint, allow_http),
+                ..ObjectStoreParams::with_aws_credentials(aws_creds, aws_region)
+            }),
             mode: mode,
             ..WriteParams::default()
         };
@@ -92,8 +186,15 @@ impl JsTable {
         let js_table = cx.this().downcast_or_throw::<JsBox<JsTable>, _>(&mut cx)?;
         let buffer = cx.argument::<JsBuffer>(0)?;
         let write_mode = cx.argument::<JsString>(1)?.value(&mut cx);
+        let bytes_written = buffer.as_slice(&mut cx).len() as u64;
         let (batches, schema) =
             arrow_buffer_to_record_batch(buffer.as_slice(&mut cx)).or_throw(&mut cx)?;
+        let dictionary_encode = get_bool_arg(&mut cx, 8);
+        let batches = maybe_dictionary_encode(batches, dictionary_encode).or_throw(&mut cx)?;
+        let schema = batches
+            .first()
+            .map(|b| b.schema())
+            .unwrap_or(schema);
         let rt = runtime(&mut cx)?;
         let channel = cx.channel();
         let mut table = js_table.table.clone();
@@ -107,18 +208,124 @@ impl JsTable {
         };
         let aws_creds = get_aws_creds(&mut cx, 2)?;
         let aws_region = get_aws_region(&mut cx, 5)?;
+        let aws_endpoint = get_aws_endpoint(&mut cx, 6)?;
+        let allow_http = get_aws_allow_http(&mut cx, 7)?;
 
         let params = WriteParams {
-            store_params: Some(ObjectStoreParams::with_aws_credentials(
-                aws_creds, aws_region,
-            )),
+            store_params: Some(ObjectStoreParams {
+                storage_options: s3_storage_options(aws_region.clone(), aws_endpoint, allow_http),
+                ..ObjectStoreParams::with_aws_credentials(aws_creds, aws_region)
+            }),
             mode: write_mode,
             ..WriteParams::default()
         };
 
+        let table_name = table.name().to_string();
         rt.spawn(async move {
             let batch_reader = RecordBatchIterator::new(batches.into_iter().map(Ok), schema);
             let add_result = table.add(batch_reader, Some(params)).await;
+            if add_result.is_ok() {
+                // There's no dedicated "bytes written" counter; the
+                // generic per-op IO-bytes counter tracks data volume for
+                // this add the same way it would for a read, just under
+                // op="add" instead of op="search".
+                vectordb::metrics::global_registry().record_io_bytes_read(
+                    &table_name,
+                    "add",
+                    bytes_written,
+                );
+            }
+
+            deferred.settle_with(&channel, move |mut cx| {
+                let _added = add_result.or_throw(&mut cx)?;
+                Ok(cx.boxed(JsTable::from(table)))
+            });
+        });
+        Ok(promise)
+    }
+
+    /// Like [`Self::js_create`], but `buffer_chunks` holds the Arrow IPC
+    /// *streaming* format instead of the whole-dataset format
+    /// `arrow_buffer_to_record_batch` expects, split into a JS array of
+    /// buffers rather than one pre-joined buffer. Chunks are read through
+    /// [`ChunkedReader`] and batches are decoded one at a time by
+    /// [`StreamReader`] and fed straight into the writer as they are read,
+    /// so a multi-gigabyte ingest never needs the whole stream resident in
+    /// memory as one contiguous allocation — only each individual chunk and
+    /// decoded batch are.
+    pub(crate) fn js_create_stream(mut cx: FunctionContext) -> JsResult<JsPromise> {
+        let db = cx
+            .this()
+            .downcast_or_throw::<JsBox<JsDatabase>, _>(&mut cx)?;
+        let table_name = cx.argument::<JsString>(0)?.value(&mut cx);
+        let chunks = read_buffer_chunks(&mut cx, 1)?;
+
+        let mode = match cx.argument::<JsString>(2)?.value(&mut cx).as_str() {
+            "overwrite" => WriteMode::Overwrite,
+            "append" => WriteMode::Append,
+            "create" => WriteMode::Create,
+            _ => {
+                return cx.throw_error(
+                    "Table::create only supports 'overwrite', 'append' and 'create' modes",
+                )
+            }
+        };
+        let stream_reader = match StreamReader::try_new(ChunkedReader::new(chunks), None) {
+            Ok(reader) => reader,
+            Err(e) => return cx.throw_error(format!("invalid Arrow IPC stream: {}", e)),
+        };
+
+        let rt = runtime(&mut cx)?;
+        let channel = cx.channel();
+        let (deferred, promise) = cx.promise();
+        let database = db.database.clone();
+
+        let params = WriteParams {
+            mode,
+            ..WriteParams::default()
+        };
+
+        rt.spawn(async move {
+            let table_rst = database
+                .create_table(&table_name, stream_reader, Some(params))
+                .await;
+
+            deferred.settle_with(&channel, move |mut cx| {
+                let table = table_rst.or_throw(&mut cx)?;
+                Ok(cx.boxed(JsTable::from(table)))
+            });
+        });
+        Ok(promise)
+    }
+
+    /// Streaming counterpart to [`Self::js_add`]; see [`Self::js_create_stream`].
+    pub(crate) fn js_add_stream(mut cx: FunctionContext) -> JsResult<JsPromise> {
+        let js_table = cx.this().downcast_or_throw::<JsBox<JsTable>, _>(&mut cx)?;
+        let chunks = read_buffer_chunks(&mut cx, 0)?;
+        let write_mode = cx.argument::<JsString>(1)?.value(&mut cx);
+        let write_mode = match write_mode.as_str() {
+            "create" => WriteMode::Create,
+            "append" => WriteMode::Append,
+            "overwrite" => WriteMode::Overwrite,
+            s => return cx.throw_error(format!("invalid write mode {}", s)),
+        };
+        let stream_reader = match StreamReader::try_new(ChunkedReader::new(chunks), None) {
+            Ok(reader) => reader,
+            Err(e) => return cx.throw_error(format!("invalid Arrow IPC stream: {}", e)),
+        };
+
+        let rt = runtime(&mut cx)?;
+        let channel = cx.channel();
+        let mut table = js_table.table.clone();
+
+        let (deferred, promise) = cx.promise();
+        let params = WriteParams {
+            mode: write_mode,
+            ..WriteParams::default()
+        };
+
+        rt.spawn(async move {
+            let add_result = table.add(stream_reader, Some(params)).await;
 
             deferred.settle_with(&channel, move |mut cx| {
                 let _added = add_result.or_throw(&mut cx)?;
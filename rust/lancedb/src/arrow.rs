@@ -22,7 +22,7 @@ use futures::{Stream, StreamExt};
 use {
     polars::datatypes,
     polars::frame::ArrowChunk,
-    polars::prelude::{DataFrame, Field, Schema, Series},
+    polars::prelude::{DataFrame, Field, IntoLazy, LazyFrame, Schema, Series},
     polars_arrow::array,
 };
 
@@ -128,6 +128,207 @@ impl<T: arrow_array::RecordBatchReader + Send + 'static> IntoArrow for T {
     }
 }
 
+/// Options controlling automatic dictionary-encoding of low-cardinality
+/// `Utf8`/`LargeUtf8` columns on write (see [`dictionary_encode_batch`]).
+#[derive(Debug, Clone)]
+pub struct DictionaryEncodingOptions {
+    /// A column is rebuilt as `Dictionary(Int32, Utf8)` when
+    /// `distinct_values / num_rows` falls below this threshold.
+    pub cardinality_ratio_threshold: f64,
+    /// If set, only these columns are considered for encoding; all other
+    /// string columns are left as-is. If `None`, every `Utf8`/`LargeUtf8`
+    /// column is a candidate.
+    pub columns: Option<Vec<String>>,
+}
+
+impl Default for DictionaryEncodingOptions {
+    fn default() -> Self {
+        Self {
+            cardinality_ratio_threshold: 0.5,
+            columns: None,
+        }
+    }
+}
+
+/// Decides which `Utf8`/`LargeUtf8` columns of `batches` qualify for
+/// dictionary-encoding under `options`, by pooling cardinality across every
+/// batch rather than judging each batch in isolation.
+///
+/// This is the piece that must run once per multi-batch `add`/`create`
+/// call rather than once per batch: if batch 1 alone looks low-cardinality
+/// but batch 2 alone looks high-cardinality, deciding independently would
+/// dictionary-encode the column in one batch and not the other, producing
+/// two different schemas even though [`encode_selected_columns`] is applied
+/// to both. Pooling first guarantees one decision, and therefore one
+/// schema, for the whole call.
+fn select_dictionary_columns(
+    batches: &[arrow_array::RecordBatch],
+    options: &DictionaryEncodingOptions,
+) -> Vec<String> {
+    use arrow_array::Array;
+    use arrow_schema::DataType;
+    use std::collections::HashSet;
+
+    let Some(schema) = batches.first().map(|b| b.schema()) else {
+        return Vec::new();
+    };
+
+    let mut selected = Vec::new();
+    for field in schema.fields() {
+        let is_candidate = matches!(field.data_type(), DataType::Utf8 | DataType::LargeUtf8)
+            && options
+                .columns
+                .as_ref()
+                .map(|cols| cols.iter().any(|c| c == field.name()))
+                .unwrap_or(true);
+        if !is_candidate {
+            continue;
+        }
+
+        let mut distinct: HashSet<&str> = HashSet::new();
+        let mut total = 0usize;
+        for batch in batches {
+            let Some(column) = batch.column_by_name(field.name()) else {
+                continue;
+            };
+            for value in string_values(column, field.data_type()) {
+                total += 1;
+                if let Some(value) = value {
+                    distinct.insert(value);
+                }
+            }
+        }
+
+        if total > 0 && (distinct.len() as f64 / total as f64) < options.cardinality_ratio_threshold
+        {
+            selected.push(field.name().clone());
+        }
+    }
+    selected
+}
+
+/// Reads `column` (expected to be `data_type`) as a sequence of optional
+/// string values, regardless of whether it's `Utf8` or `LargeUtf8`.
+fn string_values<'a>(
+    column: &'a arrow_array::ArrayRef,
+    data_type: &arrow_schema::DataType,
+) -> Vec<Option<&'a str>> {
+    use arrow_array::{Array, LargeStringArray, StringArray};
+    use arrow_schema::DataType;
+
+    match data_type {
+        DataType::Utf8 => {
+            let arr = column.as_any().downcast_ref::<StringArray>().unwrap();
+            (0..arr.len())
+                .map(|i| arr.is_valid(i).then(|| arr.value(i)))
+                .collect()
+        }
+        DataType::LargeUtf8 => {
+            let arr = column.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            (0..arr.len())
+                .map(|i| arr.is_valid(i).then(|| arr.value(i)))
+                .collect()
+        }
+        _ => unreachable!("string_values called on a non-string column"),
+    }
+}
+
+/// Rebuilds each column of `batch` named in `columns` as a
+/// `Dictionary(Int32, Utf8)` column, leaving every other column untouched.
+///
+/// Unlike [`select_dictionary_columns`], this never looks at `batch`'s own
+/// cardinality: `columns` is expected to already be the result of a single
+/// shared decision (see [`dictionary_encode_batches`]), so every batch a
+/// call is applied to ends up with the same schema.
+fn encode_selected_columns(
+    batch: &arrow_array::RecordBatch,
+    columns: &[String],
+) -> Result<arrow_array::RecordBatch> {
+    use arrow_array::{DictionaryArray, StringArray};
+    use arrow_schema::{DataType, Field};
+    use std::collections::HashMap;
+
+    let schema = batch.schema();
+    let mut fields = Vec::with_capacity(schema.fields().len());
+    let mut out_columns: Vec<arrow_array::ArrayRef> = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns().iter()) {
+        if !columns.iter().any(|c| c == field.name()) {
+            fields.push(field.clone());
+            out_columns.push(column.clone());
+            continue;
+        }
+
+        let values = string_values(column, field.data_type());
+
+        let mut dictionary: HashMap<&str, i32> = HashMap::new();
+        for value in values.iter().flatten() {
+            if !dictionary.contains_key(value) {
+                let next_index = dictionary.len() as i32;
+                dictionary.insert(value, next_index);
+            }
+        }
+
+        let mut dict_values: Vec<&str> = vec![""; dictionary.len()];
+        for (value, index) in &dictionary {
+            dict_values[*index as usize] = value;
+        }
+        let keys: Vec<Option<i32>> = values
+            .iter()
+            .map(|v| v.map(|value| dictionary[value]))
+            .collect();
+        let dict_array = DictionaryArray::<arrow_array::types::Int32Type>::new(
+            arrow_array::Int32Array::from(keys),
+            std::sync::Arc::new(StringArray::from(dict_values)),
+        );
+
+        let new_data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        fields.push(std::sync::Arc::new(Field::new(
+            field.name(),
+            new_data_type,
+            field.is_nullable(),
+        )));
+        out_columns.push(std::sync::Arc::new(dict_array));
+    }
+
+    let new_schema = std::sync::Arc::new(arrow_schema::Schema::new(fields));
+    arrow_array::RecordBatch::try_new(new_schema, out_columns).map_err(|e| e.into())
+}
+
+/// Rebuilds each `Utf8`/`LargeUtf8` column of `batch` that is both selected
+/// by `options.columns` (or all such columns, if unset) and whose observed
+/// cardinality ratio is below `options.cardinality_ratio_threshold` as a
+/// `Dictionary(Int32, Utf8)` column, leaving every other column untouched.
+///
+/// The decision is made from `batch` alone. When encoding more than one
+/// batch from the same call (e.g. a multi-batch `add`), use
+/// [`dictionary_encode_batches`] instead so the decision — and therefore
+/// the resulting schema — is the same for every batch.
+pub fn dictionary_encode_batch(
+    batch: &arrow_array::RecordBatch,
+    options: &DictionaryEncodingOptions,
+) -> Result<arrow_array::RecordBatch> {
+    let columns = select_dictionary_columns(std::slice::from_ref(batch), options);
+    encode_selected_columns(batch, &columns)
+}
+
+/// Dictionary-encodes every batch in `batches` with a single shared
+/// decision of which columns qualify (see [`select_dictionary_columns`]),
+/// so every batch ends up with the same schema — unlike calling
+/// [`dictionary_encode_batch`] once per batch, which can dictionary-encode
+/// a column in one batch and not another depending on that batch's own
+/// cardinality.
+pub fn dictionary_encode_batches(
+    batches: &[arrow_array::RecordBatch],
+    options: &DictionaryEncodingOptions,
+) -> Result<Vec<arrow_array::RecordBatch>> {
+    let columns = select_dictionary_columns(batches, options);
+    batches
+        .iter()
+        .map(|batch| encode_selected_columns(batch, &columns))
+        .collect()
+}
+
 /// When interpreting Polars dataframes as polars-arrow record batches,
 /// whether to use Arrow string/binary view types instead of the standard
 /// Arrow string/binary types.
@@ -202,6 +403,16 @@ impl arrow_array::RecordBatchReader for PolarsDataFrameRecordBatchReader {
 #[cfg(feature = "polars")]
 pub trait IntoPolars {
     fn into_polars(&mut self) -> impl std::future::Future<Output = Result<DataFrame>> + Send;
+
+    /// Like [`Self::into_polars`], but never materializes the full result in
+    /// memory. Each `RecordBatch` is converted to a small `DataFrame` via
+    /// [`convert_record_batch_to_polars_df`] as it arrives; callers can push
+    /// filters/projections/limits down onto the yielded stream instead of
+    /// waiting for every batch to be collected. Useful when a vector search
+    /// returns more rows than comfortably fit in memory at once.
+    fn into_polars_stream(
+        self,
+    ) -> Pin<Box<dyn Stream<Item = Result<DataFrame>> + Send>>;
 }
 
 #[cfg(feature = "polars")]
@@ -216,6 +427,37 @@ impl IntoPolars for SendableRecordBatchStream {
         }
         Ok(acc_df)
     }
+
+    fn into_polars_stream(self) -> Pin<Box<dyn Stream<Item = Result<DataFrame>> + Send>> {
+        let arrow_schema = self.schema();
+        let polars_schema = convert_arrow_schema_to_polars_schema(&arrow_schema);
+        Box::pin(self.map(move |record_batch| {
+            let record_batch = record_batch?;
+            convert_record_batch_to_polars_df(&record_batch, &polars_schema)
+        }))
+    }
+}
+
+/// Folds a stream of per-batch `DataFrame`s (as produced by
+/// [`IntoPolars::into_polars_stream`]) into a single Polars [`LazyFrame`],
+/// so filters/projections/limits applied to the result are planned
+/// across all batches at once.
+///
+/// Batches are pulled from `stream` and folded into the union plan one at a
+/// time. Each batch is converted to an in-memory `DataFrame` that the plan
+/// keeps, so the returned `LazyFrame` holds every row of the result in
+/// memory; it is not a streaming scan over `stream`.
+#[cfg(feature = "polars")]
+pub async fn into_polars_lazy(stream: SendableRecordBatchStream) -> Result<LazyFrame> {
+    let mut chunks = stream.into_polars_stream();
+    let Some(first) = chunks.next().await else {
+        return Ok(LazyFrame::default());
+    };
+    let mut acc = first?.lazy();
+    while let Some(chunk) = chunks.next().await {
+        acc = polars::prelude::concat([acc, chunk?.lazy()], polars::prelude::UnionArgs::default())?;
+    }
+    Ok(acc)
 }
 
 #[cfg(feature = "polars")]
@@ -250,9 +492,12 @@ fn convert_record_batch_to_polars_df(
 mod tests {
     use super::SendableRecordBatchStream;
     use crate::arrow::{
-        IntoArrow, IntoPolars, PolarsDataFrameRecordBatchReader, SimpleRecordBatchStream,
+        into_polars_lazy, IntoArrow, IntoPolars, PolarsDataFrameRecordBatchReader,
+        SimpleRecordBatchStream,
     };
+    use futures::StreamExt;
     use polars::df;
+    use polars::prelude::IntoLazy;
 
     fn get_record_batch_reader_from_polars() -> Box<dyn arrow_array::RecordBatchReader + Send> {
         let df1 = df!("string" => &["ab"],
@@ -329,4 +574,149 @@ mod tests {
             ]
         );
     }
+
+    #[tokio::test]
+    async fn from_arrow_to_polars_stream_yields_one_df_per_batch() {
+        let record_batch_reader = get_record_batch_reader_from_polars();
+        let schema = record_batch_reader.schema();
+        let stream: SendableRecordBatchStream = Box::pin(SimpleRecordBatchStream {
+            schema: schema.clone(),
+            stream: futures::stream::iter(
+                record_batch_reader
+                    .into_iter()
+                    .map(|r| r.map_err(Into::into)),
+            ),
+        });
+
+        let dfs: Vec<_> = stream
+            .into_polars_stream()
+            .map(|df| df.unwrap())
+            .collect()
+            .await;
+        assert_eq!(dfs.len(), 2);
+        assert_eq!(dfs[0].height(), 1);
+        assert_eq!(dfs[1].height(), 1);
+    }
+
+    #[tokio::test]
+    async fn from_arrow_to_polars_lazy_preserves_all_rows() {
+        let record_batch_reader = get_record_batch_reader_from_polars();
+        let schema = record_batch_reader.schema();
+        let stream: SendableRecordBatchStream = Box::pin(SimpleRecordBatchStream {
+            schema: schema.clone(),
+            stream: futures::stream::iter(
+                record_batch_reader
+                    .into_iter()
+                    .map(|r| r.map_err(Into::into)),
+            ),
+        });
+
+        let lazy = into_polars_lazy(stream).await.unwrap();
+        let df = lazy.collect().unwrap();
+        assert_eq!(df.height(), 2);
+    }
+}
+
+#[cfg(test)]
+mod dictionary_encoding_tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+
+    use super::{dictionary_encode_batch, dictionary_encode_batches, DictionaryEncodingOptions};
+
+    fn batch_with_repeated_category(rows: usize) -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("category", DataType::Utf8, false),
+        ]));
+        let ids: Vec<i64> = (0..rows as i64).collect();
+        let categories: Vec<&str> = (0..rows).map(|i| if i % 2 == 0 { "a" } else { "b" }).collect();
+        RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(ids)),
+                Arc::new(StringArray::from(categories)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn low_cardinality_column_is_dictionary_encoded() {
+        let batch = batch_with_repeated_category(10);
+        let encoded = dictionary_encode_batch(&batch, &DictionaryEncodingOptions::default()).unwrap();
+        assert_eq!(
+            encoded.schema().field(1).data_type(),
+            &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        );
+        // Non-string columns are untouched.
+        assert_eq!(encoded.schema().field(0).data_type(), &DataType::Int64);
+        assert_eq!(encoded.num_rows(), batch.num_rows());
+    }
+
+    #[test]
+    fn high_cardinality_column_is_left_unchanged() {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Utf8, false)]));
+        let values: Vec<String> = (0..10).map(|i| format!("unique-{i}")).collect();
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(StringArray::from(
+                values.iter().map(String::as_str).collect::<Vec<_>>(),
+            ))],
+        )
+        .unwrap();
+
+        let encoded = dictionary_encode_batch(&batch, &DictionaryEncodingOptions::default()).unwrap();
+        assert_eq!(encoded.schema().field(0).data_type(), &DataType::Utf8);
+    }
+
+    #[test]
+    fn column_allow_list_restricts_encoding() {
+        let batch = batch_with_repeated_category(10);
+        let options = DictionaryEncodingOptions {
+            cardinality_ratio_threshold: 0.5,
+            columns: Some(vec!["id".to_string()]),
+        };
+        let encoded = dictionary_encode_batch(&batch, &options).unwrap();
+        // "category" is a candidate but not in the allow-list, so it's left alone.
+        assert_eq!(encoded.schema().field(1).data_type(), &DataType::Utf8);
+    }
+
+    #[test]
+    fn multi_batch_encoding_decision_is_shared_across_batches() {
+        // Batch 1 alone looks low-cardinality (all "a"); batch 2 alone looks
+        // high-cardinality (all distinct). Deciding per batch would encode
+        // "category" as a dictionary in batch 1 but not batch 2, leaving the
+        // two batches with different schemas.
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "category",
+            DataType::Utf8,
+            false,
+        )]));
+        let low_cardinality = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(StringArray::from(vec!["a"; 10]))],
+        )
+        .unwrap();
+        let high_cardinality = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(StringArray::from(
+                (0..10).map(|i| format!("unique-{i}")).collect::<Vec<_>>(),
+            ))],
+        )
+        .unwrap();
+
+        let encoded = dictionary_encode_batches(
+            &[low_cardinality, high_cardinality],
+            &DictionaryEncodingOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(encoded[0].schema(), encoded[1].schema());
+        // Pooled across both batches, "category" is 11/20 distinct, i.e.
+        // above the 0.5 threshold, so neither batch is encoded.
+        assert_eq!(encoded[0].schema().field(0).data_type(), &DataType::Utf8);
+    }
 }
@@ -0,0 +1,456 @@
+// Copyright 2024 LanceDB Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An [`Catalog`] backed by an [Apache Iceberg REST catalog][spec].
+//!
+//! This lets a lakehouse that already tracks Iceberg tables in a REST
+//! catalog discover Lance tables alongside them. Only the subset of the
+//! protocol needed to list, load, and commit a table pointer is implemented;
+//! LanceDB tables are registered as Iceberg tables whose current metadata
+//! file simply points at the location of the Lance dataset.
+//!
+//! [spec]: https://iceberg.apache.org/spec/#rest-catalog-api
+
+use std::sync::Arc;
+
+use arrow_schema::{DataType, Schema};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use crate::catalog::{Catalog, CatalogTableInfo, CommitOutcome};
+use crate::error::{Error, Result};
+
+/// A connection to an Apache Iceberg REST catalog, scoped to a single
+/// namespace.
+#[derive(Debug, Clone)]
+pub struct IcebergRestCatalog {
+    client: reqwest::Client,
+    /// Base URI of the catalog, e.g. `https://catalog.example.com`.
+    catalog_uri: String,
+    /// Dot-separated namespace, e.g. `"warehouse.default"`.
+    namespace: String,
+}
+
+impl IcebergRestCatalog {
+    /// Connects to the Iceberg REST catalog at `catalog_uri`, scoped to
+    /// `namespace`. This does not perform any network requests, mirroring
+    /// [`crate::connection::Connection::connect`]. The namespace is never
+    /// created by this client; it must already exist in the catalog.
+    pub fn connect(catalog_uri: impl Into<String>, namespace: impl Into<String>) -> Result<Self> {
+        let catalog_uri = catalog_uri.into();
+        let _ = url::Url::parse(&catalog_uri).map_err(|e| Error::InvalidInput {
+            message: format!("invalid Iceberg catalog URI '{}': {}", catalog_uri, e),
+        })?;
+        Ok(Self {
+            client: reqwest::Client::new(),
+            catalog_uri,
+            namespace: namespace.into(),
+        })
+    }
+
+    fn namespace_path(&self) -> String {
+        // The REST spec joins multi-level namespaces with the unit separator
+        // (0x1F) in the URL path.
+        self.namespace.replace('.', "\u{1f}")
+    }
+
+    fn tables_uri(&self) -> String {
+        format!(
+            "{}/v1/namespaces/{}/tables",
+            self.catalog_uri,
+            self.namespace_path()
+        )
+    }
+
+    fn table_uri(&self, name: &str) -> String {
+        format!("{}/{}", self.tables_uri(), name)
+    }
+
+    /// Translates an Arrow schema into the Iceberg schema JSON used in the
+    /// table registration payload. Field IDs are assigned sequentially,
+    /// which is sufficient for tables LanceDB creates itself (Iceberg does
+    /// not require field IDs to be stable across unrelated catalogs).
+    fn arrow_schema_to_iceberg(schema: &Schema) -> Value {
+        let fields: Vec<Value> = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                json!({
+                    "id": i + 1,
+                    "name": field.name(),
+                    "required": !field.is_nullable(),
+                    "type": iceberg_type_name(field.data_type()),
+                })
+            })
+            .collect();
+        json!({
+            "type": "struct",
+            "schema-id": 0,
+            "fields": fields,
+        })
+    }
+}
+
+/// `register_lance_table` points the catalog at
+/// "{dataset_location}/metadata/lance.json"; recover the dataset's own
+/// location by stripping that suffix back off.
+fn dataset_location_from_metadata_location(metadata_location: &str) -> String {
+    metadata_location
+        .strip_suffix("/metadata/lance.json")
+        .unwrap_or(metadata_location)
+        .to_string()
+}
+
+fn iceberg_type_name(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::Boolean => "boolean",
+        DataType::Int32 => "int",
+        DataType::Int64 => "long",
+        DataType::Float32 => "float",
+        DataType::Float64 => "double",
+        DataType::Utf8 | DataType::LargeUtf8 => "string",
+        DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => "binary",
+        DataType::Date32 | DataType::Date64 => "date",
+        DataType::Timestamp(_, _) => "timestamptz",
+        // Anything else (lists, vectors, structs, ...) is stored as the raw
+        // Lance dataset anyway; the Iceberg side only needs a placeholder
+        // type so the schema round-trips through catalogs that validate it.
+        _ => "binary",
+    }
+}
+
+#[async_trait]
+impl Catalog for IcebergRestCatalog {
+    async fn table_names(&self) -> Result<Vec<String>> {
+        let response = self
+            .client
+            .get(self.tables_uri())
+            .send()
+            .await
+            .map_err(|e| Error::Http {
+                message: format!("failed to list Iceberg tables: {}", e),
+            })?;
+        let body: Value = response.json().await.map_err(|e| Error::Http {
+            message: format!("failed to parse Iceberg catalog response: {}", e),
+        })?;
+        let identifiers = body["identifiers"].as_array().cloned().unwrap_or_default();
+        Ok(identifiers
+            .into_iter()
+            .filter_map(|id| id["name"].as_str().map(str::to_string))
+            .collect())
+    }
+
+    async fn load_table(&self, name: &str) -> Result<Option<CatalogTableInfo>> {
+        let response = self
+            .client
+            .get(self.table_uri(name))
+            .send()
+            .await
+            .map_err(|e| Error::Http {
+                message: format!("failed to load Iceberg table '{}': {}", name, e),
+            })?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let body: Value = response.json().await.map_err(|e| Error::Http {
+            message: format!("failed to parse Iceberg table response: {}", e),
+        })?;
+        let metadata_location = body["metadata-location"]
+            .as_str()
+            .ok_or_else(|| Error::Runtime {
+                message: format!("Iceberg catalog response for '{}' is missing metadata-location", name),
+            })?
+            .to_string();
+        Ok(Some(CatalogTableInfo {
+            name: name.to_string(),
+            metadata_location,
+        }))
+    }
+
+    async fn open_table(&self, name: &str) -> Result<Option<String>> {
+        let Some(info) = self.load_table(name).await? else {
+            return Ok(None);
+        };
+        Ok(Some(dataset_location_from_metadata_location(
+            &info.metadata_location,
+        )))
+    }
+
+    async fn commit_table(
+        &self,
+        name: &str,
+        metadata_location: &str,
+        schema: &Schema,
+        expected_metadata_location: Option<&str>,
+    ) -> Result<CommitOutcome> {
+        // The REST spec expresses optimistic concurrency as a list of
+        // requirements checked server-side against the table's current
+        // state; `assert-current-metadata-location` fails the commit (409)
+        // if another writer has already moved the pointer.
+        let mut requirements = Vec::new();
+        if let Some(expected) = expected_metadata_location {
+            requirements.push(json!({
+                "type": "assert-current-metadata-location",
+                "current-metadata-location": expected,
+            }));
+        } else {
+            requirements.push(json!({"type": "assert-create"}));
+        }
+
+        let iceberg_schema = Self::arrow_schema_to_iceberg(schema);
+        let body = json!({
+            "identifier": {"namespace": [self.namespace], "name": name},
+            "requirements": requirements,
+            "updates": [
+                {
+                    "action": "add-schema",
+                    "schema": iceberg_schema,
+                },
+                {
+                    "action": "set-current-schema",
+                    "schema-id": -1,
+                },
+                {
+                    "action": "set-location",
+                    "metadata-location": metadata_location,
+                },
+            ],
+        });
+
+        let response = self
+            .client
+            .post(self.tables_uri())
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| Error::Http {
+                message: format!("failed to commit Iceberg table '{}': {}", name, e),
+            })?;
+
+        if response.status() == reqwest::StatusCode::CONFLICT {
+            return Ok(CommitOutcome::Conflict);
+        }
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(Error::Runtime {
+                message: format!("Iceberg catalog commit for '{}' failed ({}): {}", name, status, text),
+            });
+        }
+        Ok(CommitOutcome::Committed)
+    }
+}
+
+/// Registers a Lance dataset's schema under `name` in the given catalog,
+/// retrying once on a detected concurrent commit.
+///
+/// This is the glue a `Database`/`Connection` implementation would call
+/// after writing a new Lance dataset version, to keep the catalog's pointer
+/// in sync with the dataset without requiring the caller to hand-roll the
+/// retry loop.
+pub async fn register_lance_table(
+    catalog: &Arc<dyn Catalog>,
+    name: &str,
+    schema: &Schema,
+    dataset_location: &str,
+) -> Result<()> {
+    let metadata_location = format!("{}/metadata/lance.json", dataset_location);
+
+    let existing = catalog.load_table(name).await?;
+    let expected = existing.as_ref().map(|info| info.metadata_location.as_str());
+    match catalog
+        .commit_table(name, &metadata_location, schema, expected)
+        .await?
+    {
+        CommitOutcome::Committed => Ok(()),
+        CommitOutcome::Conflict => {
+            // Another writer raced us; re-read the current pointer and retry
+            // exactly once with the fresh expected value. A second conflict
+            // is a real failure, not silently swallowed.
+            let refreshed = catalog.load_table(name).await?;
+            let refreshed_expected = refreshed.as_ref().map(|info| info.metadata_location.as_str());
+            match catalog
+                .commit_table(name, &metadata_location, schema, refreshed_expected)
+                .await?
+            {
+                CommitOutcome::Committed => Ok(()),
+                CommitOutcome::Conflict => Err(Error::Runtime {
+                    message: format!(
+                        "concurrent commit detected for table '{}' even after retrying with a refreshed metadata location",
+                        name
+                    ),
+                }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use arrow_schema::Field;
+
+    use super::*;
+
+    fn sample_schema() -> Schema {
+        Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ])
+    }
+
+    #[test]
+    fn arrow_schema_to_iceberg_assigns_sequential_field_ids() {
+        let schema = sample_schema();
+        let iceberg = IcebergRestCatalog::arrow_schema_to_iceberg(&schema);
+        assert_eq!(iceberg["type"], "struct");
+        let fields = iceberg["fields"].as_array().unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0]["id"], 1);
+        assert_eq!(fields[0]["name"], "id");
+        assert_eq!(fields[0]["required"], true);
+        assert_eq!(fields[0]["type"], "long");
+        assert_eq!(fields[1]["id"], 2);
+        assert_eq!(fields[1]["required"], false);
+        assert_eq!(fields[1]["type"], "string");
+    }
+
+    #[test]
+    fn namespace_path_joins_levels_with_unit_separator() {
+        let catalog = IcebergRestCatalog::connect("https://catalog.example.com", "warehouse.default").unwrap();
+        assert_eq!(catalog.namespace_path(), "warehouse\u{1f}default");
+        assert_eq!(
+            catalog.tables_uri(),
+            "https://catalog.example.com/v1/namespaces/warehouse\u{1f}default/tables"
+        );
+    }
+
+    /// A `Catalog` double that records calls and lets a test script each
+    /// `commit_table` response, so `register_lance_table`'s retry-once
+    /// logic can be exercised without a real HTTP server.
+    #[derive(Debug)]
+    struct MockCatalog {
+        commit_attempts: AtomicUsize,
+        commit_results: Mutex<Vec<Result<CommitOutcome>>>,
+        load_result: CatalogTableInfo,
+    }
+
+    #[async_trait]
+    impl Catalog for MockCatalog {
+        async fn table_names(&self) -> Result<Vec<String>> {
+            Ok(vec![self.load_result.name.clone()])
+        }
+
+        async fn load_table(&self, _name: &str) -> Result<Option<CatalogTableInfo>> {
+            Ok(Some(self.load_result.clone()))
+        }
+
+        async fn open_table(&self, name: &str) -> Result<Option<String>> {
+            Ok(self
+                .load_table(name)
+                .await?
+                .map(|info| info.metadata_location))
+        }
+
+        async fn commit_table(
+            &self,
+            _name: &str,
+            _metadata_location: &str,
+            _schema: &Schema,
+            _expected_metadata_location: Option<&str>,
+        ) -> Result<CommitOutcome> {
+            let attempt = self.commit_attempts.fetch_add(1, Ordering::SeqCst);
+            let mut results = self.commit_results.lock().unwrap();
+            assert!(
+                attempt < results.len(),
+                "commit_table called more times than the test expected"
+            );
+            std::mem::replace(
+                &mut results[attempt],
+                Ok(CommitOutcome::Committed),
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn register_lance_table_retries_once_on_conflict() {
+        let catalog: Arc<dyn Catalog> = Arc::new(MockCatalog {
+            commit_attempts: AtomicUsize::new(0),
+            commit_results: Mutex::new(vec![
+                Ok(CommitOutcome::Conflict),
+                Ok(CommitOutcome::Committed),
+            ]),
+            load_result: CatalogTableInfo {
+                name: "my_table".to_string(),
+                metadata_location: "s3://bucket/my_table/metadata/lance.json".to_string(),
+            },
+        });
+
+        let result = register_lance_table(&catalog, "my_table", &sample_schema(), "s3://bucket/my_table").await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn register_lance_table_fails_after_second_conflict() {
+        let catalog: Arc<dyn Catalog> = Arc::new(MockCatalog {
+            commit_attempts: AtomicUsize::new(0),
+            commit_results: Mutex::new(vec![
+                Ok(CommitOutcome::Conflict),
+                Ok(CommitOutcome::Conflict),
+            ]),
+            load_result: CatalogTableInfo {
+                name: "my_table".to_string(),
+                metadata_location: "s3://bucket/my_table/metadata/lance.json".to_string(),
+            },
+        });
+
+        let result = register_lance_table(&catalog, "my_table", &sample_schema(), "s3://bucket/my_table").await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn register_lance_table_does_not_retry_on_real_error() {
+        let catalog: Arc<dyn Catalog> = Arc::new(MockCatalog {
+            commit_attempts: AtomicUsize::new(0),
+            commit_results: Mutex::new(vec![Err(Error::Http {
+                message: "connection reset".to_string(),
+            })]),
+            load_result: CatalogTableInfo {
+                name: "my_table".to_string(),
+                metadata_location: "s3://bucket/my_table/metadata/lance.json".to_string(),
+            },
+        });
+
+        let result = register_lance_table(&catalog, "my_table", &sample_schema(), "s3://bucket/my_table").await;
+        assert!(result.is_err());
+        assert_eq!(catalog.table_names().await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn open_table_strips_metadata_suffix() {
+        assert_eq!(
+            dataset_location_from_metadata_location("s3://bucket/my_table/metadata/lance.json"),
+            "s3://bucket/my_table"
+        );
+        // A metadata location not produced by `register_lance_table` is
+        // returned unchanged rather than panicking or truncating it.
+        assert_eq!(
+            dataset_location_from_metadata_location("s3://bucket/my_table/custom.json"),
+            "s3://bucket/my_table/custom.json"
+        );
+    }
+}
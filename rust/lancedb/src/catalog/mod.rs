@@ -0,0 +1,93 @@
+// Copyright 2024 LanceDB Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optional catalog integrations.
+//!
+//! A [`Catalog`] lets a LanceDB database be discovered through, and register
+//! its tables into, an external table catalog (e.g. an Apache Iceberg REST
+//! catalog) instead of relying purely on a directory listing of the
+//! underlying object store.
+
+pub mod iceberg;
+
+use async_trait::async_trait;
+use arrow_schema::Schema;
+
+use crate::error::Result;
+
+/// Outcome of a [`Catalog::commit_table`] attempt.
+///
+/// A detected conflict is not an [`crate::error::Error`]: it's an expected
+/// outcome of optimistic concurrency that the caller (see
+/// [`iceberg::register_lance_table`]) is expected to react to by refreshing
+/// and retrying, as opposed to a genuine failure (bad auth, malformed
+/// request, unreachable catalog) which is still surfaced as `Err`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitOutcome {
+    Committed,
+    Conflict,
+}
+
+/// A handle to a table as known by a [`Catalog`].
+///
+/// This is deliberately a thin pointer: the catalog is only responsible for
+/// resolving a table name to the location of its metadata, not for reading
+/// or writing the Lance dataset itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogTableInfo {
+    pub name: String,
+    /// Location of the table's metadata pointer (e.g. an Iceberg metadata
+    /// JSON file) as understood by the catalog.
+    pub metadata_location: String,
+}
+
+/// A catalog that can discover and register LanceDB tables alongside tables
+/// from other formats.
+///
+/// Implementations are expected to be cheap to clone and safe to share
+/// across async tasks, mirroring [`crate::connection::Connection`].
+#[async_trait]
+pub trait Catalog: std::fmt::Debug + Send + Sync {
+    /// List the tables currently registered in the catalog's namespace.
+    async fn table_names(&self) -> Result<Vec<String>>;
+
+    /// Resolve a table name to its current metadata location, if it exists.
+    async fn load_table(&self, name: &str) -> Result<Option<CatalogTableInfo>>;
+
+    /// Resolves `name` to the location of its underlying Lance dataset, if
+    /// registered in the catalog. Unlike [`Self::load_table`], which returns
+    /// the catalog's own metadata pointer, this returns the location a
+    /// [`crate::connection::Connection`] can actually open as a table, so
+    /// callers can discover and open catalog-registered tables without
+    /// falling back to a directory listing.
+    async fn open_table(&self, name: &str) -> Result<Option<String>>;
+
+    /// Register a new metadata location for `name`, translating `schema`
+    /// into whatever schema representation the catalog's registration
+    /// payload requires.
+    ///
+    /// If `expected_metadata_location` is provided, the catalog must perform
+    /// an optimistic-concurrency check: the commit only succeeds if the
+    /// catalog's current metadata location for `name` still matches
+    /// `expected_metadata_location`. A detected conflict is returned as
+    /// `Ok(CommitOutcome::Conflict)`, not an `Err`, so callers can refresh
+    /// and retry rather than treating it as a genuine failure.
+    async fn commit_table(
+        &self,
+        name: &str,
+        metadata_location: &str,
+        schema: &Schema,
+        expected_metadata_location: Option<&str>,
+    ) -> Result<CommitOutcome>;
+}
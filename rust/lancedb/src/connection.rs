@@ -0,0 +1,110 @@
+// Copyright 2024 LanceDB Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A connection to a LanceDB database, optionally backed by a [`Catalog`]
+//! for table discovery instead of a plain directory listing.
+
+use std::sync::Arc;
+
+use arrow_schema::Schema;
+
+use crate::catalog::{iceberg::register_lance_table, Catalog};
+use crate::error::{Error, Result};
+
+/// A connection to a LanceDB database rooted at a URI.
+///
+/// When a [`Catalog`] is attached (via [`Connection::connect_with_catalog`]),
+/// [`Connection::open_table`] resolves table names through it instead of
+/// assuming every table lives at `{uri}/{name}.lance`, and
+/// [`Connection::register_table`] keeps the catalog's pointer in sync with
+/// newly-written dataset versions.
+#[derive(Debug, Clone)]
+pub struct Connection {
+    uri: String,
+    catalog: Option<Arc<dyn Catalog>>,
+}
+
+impl Connection {
+    /// Connects to the database at `uri` without a catalog. Tables are then
+    /// opened at the default `{uri}/{name}.lance` layout, and
+    /// [`Self::table_names`] returns an empty list, since no directory
+    /// listing of the underlying object store is performed.
+    pub fn connect(uri: impl Into<String>) -> Result<Self> {
+        let uri = uri.into();
+        if uri.is_empty() {
+            return Err(Error::InvalidInput {
+                message: "database URI must not be empty".to_string(),
+            });
+        }
+        Ok(Self { uri, catalog: None })
+    }
+
+    /// Connects to the database at `uri`, discovering and registering
+    /// tables through `catalog` instead of a directory listing.
+    pub fn connect_with_catalog(uri: impl Into<String>, catalog: Arc<dyn Catalog>) -> Result<Self> {
+        let mut conn = Self::connect(uri)?;
+        conn.catalog = Some(catalog);
+        Ok(conn)
+    }
+
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// The default on-disk/object-store location for a table named `name`,
+    /// used when no catalog is attached.
+    fn default_table_location(&self, name: &str) -> String {
+        format!("{}/{}.lance", self.uri.trim_end_matches('/'), name)
+    }
+
+    /// Lists the tables visible to this connection: the catalog's namespace
+    /// listing if one is attached, or an empty list otherwise (a plain
+    /// directory listing requires the object-store integration this crate
+    /// doesn't yet implement).
+    pub async fn table_names(&self) -> Result<Vec<String>> {
+        match &self.catalog {
+            Some(catalog) => catalog.table_names().await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Resolves `name` to the location of its Lance dataset: through the
+    /// attached catalog if one is present, falling back to the default
+    /// `{uri}/{name}.lance` layout otherwise.
+    pub async fn open_table(&self, name: &str) -> Result<String> {
+        if let Some(catalog) = &self.catalog {
+            if let Some(location) = catalog.open_table(name).await? {
+                return Ok(location);
+            }
+            return Err(Error::InvalidInput {
+                message: format!("table '{}' is not registered in the catalog", name),
+            });
+        }
+        Ok(self.default_table_location(name))
+    }
+
+    /// Registers `name`'s dataset location and `schema` with the attached
+    /// catalog, retrying once on a detected concurrent commit. A no-op when
+    /// no catalog is attached, since a directory-listed table needs no
+    /// separate registration step.
+    pub async fn register_table(&self, name: &str, schema: &Schema) -> Result<()> {
+        match &self.catalog {
+            Some(catalog) => {
+                let location = self.default_table_location(name);
+                register_lance_table(catalog, name, schema, &location).await
+            }
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2024 LanceDB Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The crate-wide error type and its conversions from the lower-level
+//! errors this crate's implementations bubble up through `?`.
+
+/// Errors returned by this crate.
+///
+/// Most variants carry a formatted `message` rather than structured fields.
+/// [`Error::Remote`] is the exception: it keeps the HTTP status and the
+/// server's correlation id as their own fields so callers can branch on
+/// them or log them as structured data, instead of having to regex-parse
+/// `message`; see [`crate::remote::client::RestfulLanceDbClient::check_response`].
+#[derive(Debug)]
+pub enum Error {
+    Http { message: String },
+    InvalidInput { message: String },
+    Runtime { message: String },
+    /// A non-success response from the LanceDB remote/cloud API.
+    Remote {
+        /// The response's numeric HTTP status code.
+        status: u16,
+        /// The server's `x-request-id` response header, if sent.
+        request_id: Option<String>,
+        /// The parsed JSON `message`/`error` field, or the raw response
+        /// body if it wasn't a JSON object with either field.
+        message: String,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Http { message } => write!(f, "{}", message),
+            Error::InvalidInput { message } => write!(f, "{}", message),
+            Error::Runtime { message } => write!(f, "{}", message),
+            Error::Remote {
+                status,
+                request_id: Some(request_id),
+                message,
+            } => write!(
+                f,
+                "HTTP {} (request id: {}): {}",
+                status, request_id, message
+            ),
+            Error::Remote {
+                status, message, ..
+            } => write!(f, "HTTP {}: {}", status, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<arrow_schema::ArrowError> for Error {
+    fn from(e: arrow_schema::ArrowError) -> Self {
+        Error::Runtime {
+            message: format!("arrow error: {}", e),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Http {
+            message: format!("request error: {}", e),
+        }
+    }
+}
+
+impl From<url::ParseError> for Error {
+    fn from(e: url::ParseError) -> Self {
+        Error::InvalidInput {
+            message: format!("invalid URL: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "polars")]
+impl From<polars::error::PolarsError> for Error {
+    fn from(e: polars::error::PolarsError) -> Self {
+        Error::Runtime {
+            message: format!("polars error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2024 LanceDB Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Export a [`SendableRecordBatchStream`] to a standard interchange format.
+//!
+//! This is the write-side complement to [`crate::arrow::IntoPolars`]:
+//! instead of collecting a query result into a Polars `DataFrame`,
+//! [`WriterFactory`] streams it batch-by-batch straight to any
+//! `std::io::Write` (a file, an in-memory buffer, or an object store
+//! writer), mirroring polars-io's `SerWriter`.
+
+use std::io::Write;
+
+use futures::StreamExt;
+
+use crate::arrow::SendableRecordBatchStream;
+use crate::error::{Error, Result};
+
+/// Options for [`WriterFactory::write_csv`].
+#[derive(Debug, Clone)]
+pub struct CsvWriteOptions {
+    pub delimiter: u8,
+    pub include_header: bool,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            include_header: true,
+        }
+    }
+}
+
+/// Options for [`WriterFactory::write_parquet`].
+#[derive(Debug, Clone, Default)]
+pub struct ParquetWriteOptions {
+    pub compression: Option<parquet::basic::Compression>,
+}
+
+/// Streams a [`SendableRecordBatchStream`] out to a standard interchange
+/// format, one batch at a time, so exporting a large query result never
+/// requires holding the full result set (or a full `DataFrame`) in memory.
+pub trait WriterFactory {
+    /// Writes every batch in the stream to `sink` as Parquet.
+    fn write_parquet(
+        self,
+        sink: impl Write + Send,
+        options: ParquetWriteOptions,
+    ) -> impl std::future::Future<Output = Result<()>> + Send;
+
+    /// Writes every batch in the stream to `sink` as delimited text.
+    fn write_csv(
+        self,
+        sink: impl Write + Send,
+        options: CsvWriteOptions,
+    ) -> impl std::future::Future<Output = Result<()>> + Send;
+
+    /// Writes every batch in the stream to `sink` as newline-delimited JSON.
+    fn write_ndjson(self, sink: impl Write + Send) -> impl std::future::Future<Output = Result<()>> + Send;
+}
+
+impl WriterFactory for SendableRecordBatchStream {
+    async fn write_parquet(mut self, sink: impl Write + Send, options: ParquetWriteOptions) -> Result<()> {
+        use parquet::arrow::ArrowWriter;
+        use parquet::file::properties::WriterProperties;
+
+        let mut props_builder = WriterProperties::builder();
+        if let Some(compression) = options.compression {
+            props_builder = props_builder.set_compression(compression);
+        }
+        let schema = self.schema();
+        let mut writer = ArrowWriter::try_new(sink, schema, Some(props_builder.build()))
+            .map_err(|e| Error::Runtime {
+                message: format!("failed to start Parquet writer: {}", e),
+            })?;
+        while let Some(batch) = self.next().await {
+            writer.write(&batch?).map_err(|e| Error::Runtime {
+                message: format!("failed to write Parquet batch: {}", e),
+            })?;
+        }
+        writer.close().map_err(|e| Error::Runtime {
+            message: format!("failed to finalize Parquet file: {}", e),
+        })?;
+        Ok(())
+    }
+
+    async fn write_csv(mut self, sink: impl Write + Send, options: CsvWriteOptions) -> Result<()> {
+        let mut writer = arrow_csv::WriterBuilder::new()
+            .with_delimiter(options.delimiter)
+            .with_header(options.include_header)
+            .build(sink);
+        while let Some(batch) = self.next().await {
+            writer.write(&batch?).map_err(|e| Error::Runtime {
+                message: format!("failed to write CSV batch: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+
+    async fn write_ndjson(mut self, sink: impl Write + Send) -> Result<()> {
+        let mut writer = arrow_json::LineDelimitedWriter::new(sink);
+        while let Some(batch) = self.next().await {
+            writer.write_batches(&[&batch?]).map_err(|e| Error::Runtime {
+                message: format!("failed to write NDJSON batch: {}", e),
+            })?;
+        }
+        writer.finish().map_err(|e| Error::Runtime {
+            message: format!("failed to finalize NDJSON output: {}", e),
+        })?;
+        Ok(())
+    }
+}
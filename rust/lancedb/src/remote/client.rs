@@ -12,15 +12,171 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::{future::Future, time::Duration};
 
+use futures::stream::{self, Stream};
+use futures::TryStreamExt;
+use rand::Rng;
 use reqwest::{
     header::{HeaderMap, HeaderValue},
-    RequestBuilder, Response,
+    RequestBuilder, Response, StatusCode,
 };
+use serde::de::DeserializeOwned;
 
 use crate::error::{Error, Result};
 
+/// A single page of a paginated list endpoint, as returned under an
+/// `items` key alongside the RFC 5988 `Link` header used to find the next
+/// page.
+#[derive(Debug, serde::Deserialize)]
+struct Page<T> {
+    items: Vec<T>,
+}
+
+/// Parses an RFC 5988 `Link` header into a `rel` -> target URI map, e.g.
+/// `<https://.../tables?cursor=abc>; rel="next"` becomes
+/// `{"next": "https://.../tables?cursor=abc"}`.
+fn parse_link_header(headers: &HeaderMap) -> HashMap<String, String> {
+    let mut links = HashMap::new();
+    let Some(value) = headers
+        .get(reqwest::header::LINK)
+        .and_then(|v| v.to_str().ok())
+    else {
+        return links;
+    };
+    for segment in value.split(',') {
+        let mut uri = None;
+        let mut rel = None;
+        for part in segment.split(';') {
+            let part = part.trim();
+            if let Some(target) = part.strip_prefix('<').and_then(|p| p.strip_suffix('>')) {
+                uri = Some(target.to_string());
+            } else if let Some(value) = part.strip_prefix("rel=") {
+                rel = Some(value.trim_matches('"').to_string());
+            }
+        }
+        if let (Some(uri), Some(rel)) = (uri, rel) {
+            links.insert(rel, uri);
+        }
+    }
+    links
+}
+
+/// Configures the retry behavior of [`RestfulLanceDbClient::send`].
+///
+/// Requests are retried on 429 (rate limited), 503 (overloaded), and
+/// transient connection errors, using full-jitter exponential backoff: for
+/// attempt `n` (0-indexed), `cap = min(max_delay, base_delay * 2^n)` and the
+/// client sleeps a uniformly random duration in `[0, cap]`. A `Retry-After`
+/// response header, when present, takes precedence over the computed
+/// backoff.
+#[derive(Clone, Debug)]
+pub struct RetryConfig {
+    /// Maximum number of attempts, including the first. `1` disables retries.
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryConfig {
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exp = 2u32.checked_pow(attempt).unwrap_or(u32::MAX);
+        let cap = self.base_delay.saturating_mul(exp).min(self.max_delay);
+        let jitter_millis = rand::thread_rng().gen_range(0..=cap.as_millis().max(1) as u64);
+        Duration::from_millis(jitter_millis)
+    }
+}
+
+/// Configures the transport-level behavior of [`RestfulLanceDbClient::try_new_with_config`].
+///
+/// The defaults match the previous hardcoded behavior, so existing callers
+/// of [`RestfulLanceDbClient::try_new`] are unaffected.
+#[derive(Clone, Debug)]
+pub struct ClientConfig {
+    /// Disables gzip/brotli/zstd/deflate response (de)compression. Leave
+    /// this off (the default) unless CPU, not bandwidth, is the bottleneck.
+    pub disable_compression: bool,
+    /// Timeout for establishing the underlying TCP/TLS connection. `None`
+    /// uses `reqwest`'s own default (no connect-specific timeout, only the
+    /// overall `request_timeout` applies).
+    pub connect_timeout: Option<Duration>,
+    /// Timeout for the entire request, from connect through reading the
+    /// full response body.
+    pub request_timeout: Duration,
+    /// Proxy to route all requests through, e.g. for a corporate egress
+    /// proxy. `None` lets `reqwest` fall back to the usual `HTTP_PROXY` /
+    /// `HTTPS_PROXY` environment variables.
+    pub proxy: Option<reqwest::Proxy>,
+    /// Additional PEM-encoded root certificate to trust, for connecting to
+    /// a host whose TLS certificate is signed by a private/internal CA.
+    /// This is added alongside, not instead of, the platform's default
+    /// trust store.
+    pub root_cert_pem: Option<Vec<u8>>,
+    /// How long an idle pooled connection is kept before being closed.
+    /// `None` uses `reqwest`'s own default.
+    pub pool_idle_timeout: Option<Duration>,
+    /// Maximum number of idle connections kept open per host. `None` uses
+    /// `reqwest`'s own default.
+    pub pool_max_idle_per_host: Option<usize>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            disable_compression: false,
+            connect_timeout: None,
+            request_timeout: Duration::from_secs(30),
+            proxy: None,
+            root_cert_pem: None,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+        }
+    }
+}
+
+fn retry_after_delay(response: &Response, max_delay: Duration) -> Option<Duration> {
+    let value = response.headers().get("Retry-After")?.to_str().ok()?;
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds).min(max_delay));
+    }
+    let target = httpdate::parse_http_date(value).ok()?;
+    let delay = target
+        .duration_since(std::time::SystemTime::now())
+        .unwrap_or_default();
+    Some(delay.min(max_delay))
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::SERVICE_UNAVAILABLE
+}
+
+/// Whether retrying a request with `method` can't cause a duplicate
+/// side effect. POST (table/merge-insert creation, etc.) is excluded since
+/// a retried POST that actually reached the server the first time could
+/// create or mutate something twice; GET/PUT/DELETE/HEAD/OPTIONS are safe
+/// to repeat.
+fn is_idempotent_method(method: &reqwest::Method) -> bool {
+    matches!(
+        *method,
+        reqwest::Method::GET
+            | reqwest::Method::PUT
+            | reqwest::Method::DELETE
+            | reqwest::Method::HEAD
+            | reqwest::Method::OPTIONS
+    )
+}
+
 // We use the `HttpSend` trait to abstract over the `reqwest::Client` so that
 // we can mock responses in tests. Based on the patterns from this blog post:
 // https://write.as/balrogboogie/testing-reqwest-based-clients
@@ -29,6 +185,7 @@ pub struct RestfulLanceDbClient<S: HttpSend = Sender> {
     client: reqwest::Client,
     host: String,
     sender: S,
+    retry_config: RetryConfig,
 }
 
 pub trait HttpSend: Clone + Send + Sync + std::fmt::Debug + 'static {
@@ -50,6 +207,16 @@ impl RestfulLanceDbClient<Sender> {
         api_key: &str,
         region: &str,
         host_override: Option<String>,
+    ) -> Result<Self> {
+        Self::try_new_with_config(db_url, api_key, region, host_override, ClientConfig::default())
+    }
+
+    pub fn try_new_with_config(
+        db_url: &str,
+        api_key: &str,
+        region: &str,
+        host_override: Option<String>,
+        config: ClientConfig,
     ) -> Result<Self> {
         let parsed_url = url::Url::parse(db_url)?;
         debug_assert_eq!(parsed_url.scheme(), "db");
@@ -59,15 +226,40 @@ impl RestfulLanceDbClient<Sender> {
             });
         }
         let db_name = parsed_url.host_str().unwrap();
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(30))
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.request_timeout)
             .default_headers(Self::default_headers(
                 api_key,
                 region,
                 db_name,
                 host_override.is_some(),
-            )?)
-            .build()?;
+            )?);
+        if !config.disable_compression {
+            // Each of these is a cargo feature flag on `reqwest`; enabling
+            // them both turns on transparent response decompression and
+            // advertises the matching `Accept-Encoding` value. Request
+            // bodies are sent uncompressed.
+            builder = builder.gzip(true).brotli(true).zstd(true).deflate(true);
+        }
+        if let Some(connect_timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = config.proxy {
+            builder = builder.proxy(proxy);
+        }
+        if let Some(pem) = &config.root_cert_pem {
+            let cert = reqwest::Certificate::from_pem(pem).map_err(|e| Error::Http {
+                message: format!("invalid root certificate PEM: {}", e),
+            })?;
+            builder = builder.add_root_certificate(cert);
+        }
+        if let Some(pool_idle_timeout) = config.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(pool_idle_timeout);
+        }
+        if let Some(pool_max_idle_per_host) = config.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(pool_max_idle_per_host);
+        }
+        let client = builder.build()?;
         let host = match host_override {
             Some(host_override) => host_override,
             None => format!("https://{}.{}.api.lancedb.com", db_name, region),
@@ -76,6 +268,7 @@ impl RestfulLanceDbClient<Sender> {
             client,
             host,
             sender: Sender,
+            retry_config: RetryConfig::default(),
         })
     }
 }
@@ -85,6 +278,13 @@ impl<S: HttpSend> RestfulLanceDbClient<S> {
         &self.host
     }
 
+    /// Overrides the default retry policy (3 attempts, 100ms base delay,
+    /// 30s max delay) used by [`Self::send`].
+    pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
     fn default_headers(
         api_key: &str,
         region: &str,
@@ -119,38 +319,143 @@ impl<S: HttpSend> RestfulLanceDbClient<S> {
         Ok(headers)
     }
 
+    // `uri` is usually a path relative to `self.host`, but pagination
+    // follows a `Link` header that already contains an absolute URI (see
+    // `list_all`); prepending `self.host` to that would produce garbage
+    // like "https://hosthttps://host/...", so only do it for relative URIs.
+    fn full_uri(&self, uri: &str) -> String {
+        if uri.starts_with("http://") || uri.starts_with("https://") {
+            uri.to_string()
+        } else {
+            format!("{}{}", self.host, uri)
+        }
+    }
+
     pub fn get(&self, uri: &str) -> RequestBuilder {
-        let full_uri = format!("{}{}", self.host, uri);
-        self.client.get(full_uri)
+        self.client.get(self.full_uri(uri))
     }
 
     pub fn post(&self, uri: &str) -> RequestBuilder {
-        let full_uri = format!("{}{}", self.host, uri);
-        self.client.post(full_uri)
+        self.client.post(self.full_uri(uri))
     }
 
+    /// Sends `req`, retrying on 429, 503, and errors according to
+    /// [`Self::with_retry_config`] — but only for idempotent methods (GET,
+    /// PUT, DELETE, HEAD, OPTIONS; see [`is_idempotent_method`]). A POST
+    /// (e.g. create-table, merge-insert) is sent exactly once: if it
+    /// actually reached the server before a dropped connection or timeout,
+    /// blindly retrying it could duplicate the effect, so we'd rather
+    /// surface the error than risk that.
+    ///
+    /// `RequestBuilder` is not `Clone` once a body is attached, so the
+    /// request is captured via [`RequestBuilder::try_clone`] before each
+    /// attempt; if the body is a non-replayable stream, the first attempt
+    /// is still made but no retry is possible afterward.
     pub async fn send(&self, req: RequestBuilder) -> Result<Response> {
-        self.sender.send(req).await
+        let is_idempotent = req
+            .try_clone()
+            .and_then(|b| b.build().ok())
+            .map(|built| is_idempotent_method(built.method()))
+            .unwrap_or(false);
+
+        let mut current = req;
+        let mut attempt = 0u32;
+        loop {
+            let retryable_clone = current.try_clone();
+            let result = self.sender.send(current).await;
+
+            let should_retry = is_idempotent
+                && attempt + 1 < self.retry_config.max_attempts
+                && match &result {
+                    Ok(response) => is_retryable_status(response.status()),
+                    Err(_) => true,
+                };
+            if !should_retry {
+                return result;
+            }
+
+            let Some(next) = retryable_clone else {
+                return result;
+            };
+
+            let delay = match &result {
+                Ok(response) => retry_after_delay(response, self.retry_config.max_delay)
+                    .unwrap_or_else(|| self.retry_config.backoff(attempt)),
+                Err(_) => self.retry_config.backoff(attempt),
+            };
+            tokio::time::sleep(delay).await;
+
+            current = next;
+            attempt += 1;
+        }
+    }
+
+    /// Follows `Link: <...>; rel="next"` headers returned by a list
+    /// endpoint (e.g. listing tables or versions), transparently issuing
+    /// follow-up `GET` requests until no `next` link is present. Each
+    /// page's `items` are yielded one at a time, so callers can iterate an
+    /// arbitrarily large result set without threading a cursor themselves.
+    pub fn list_all<T: DeserializeOwned + Send + 'static>(
+        &self,
+        first_uri: &str,
+    ) -> impl Stream<Item = Result<T>> + '_ {
+        let state = Some(first_uri.to_string());
+        stream::try_unfold(state, move |state| async move {
+            let Some(uri) = state else {
+                return Ok(None);
+            };
+            let response = self.send(self.get(&uri)).await?;
+            let response = self.check_response(response).await?;
+            let next_uri = parse_link_header(response.headers()).get("next").cloned();
+            let page: Page<T> = response.json().await.map_err(|e| Error::Http {
+                message: format!("failed to parse paginated response: {}", e),
+            })?;
+            Ok(Some((page.items, next_uri)))
+        })
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
     }
 
-    async fn rsp_to_str(response: Response) -> String {
+    /// Extracts the server's correlation header (if sent) and an error
+    /// message from a non-2xx response body: the `message`/`error` field of
+    /// a JSON body if present, otherwise the raw response text.
+    async fn describe_error_response(response: Response) -> (StatusCode, Option<String>, String) {
         let status = response.status();
-        response.text().await.unwrap_or_else(|_| status.to_string())
+        let request_id = response
+            .headers()
+            .get("x-request-id")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+        let body = response.text().await.unwrap_or_default();
+        let message = serde_json::from_str::<serde_json::Value>(&body)
+            .ok()
+            .and_then(|value| {
+                value
+                    .get("message")
+                    .or_else(|| value.get("error"))
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string())
+            })
+            .unwrap_or(body);
+        (status, request_id, message)
     }
 
+    /// Checks a response's status, treating any 2xx code (not just 200) as
+    /// success. On failure, returns [`Error::Remote`] with the numeric
+    /// status, the server's `x-request-id` (when sent), and either the
+    /// parsed JSON error message or the raw body kept as separate fields, so
+    /// callers can branch on the status or correlate with server-side logs
+    /// without parsing `message`.
     pub async fn check_response(&self, response: Response) -> Result<Response> {
-        let status_int: u16 = u16::from(response.status());
-        if (400..500).contains(&status_int) {
-            Err(Error::InvalidInput {
-                message: Self::rsp_to_str(response).await,
-            })
-        } else if status_int != 200 {
-            Err(Error::Runtime {
-                message: Self::rsp_to_str(response).await,
-            })
-        } else {
-            Ok(response)
+        if response.status().is_success() {
+            return Ok(response);
         }
+        let (status, request_id, message) = Self::describe_error_response(response).await;
+        Err(Error::Remote {
+            status: status.as_u16(),
+            request_id,
+            message,
+        })
     }
 }
 
@@ -196,6 +501,290 @@ pub mod test_utils {
             sender: MockSender {
                 f: Arc::new(wrapper),
             },
+            retry_config: RetryConfig::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod client_config_tests {
+    use super::{ClientConfig, RestfulLanceDbClient};
+    use std::time::Duration;
+
+    #[test]
+    fn defaults_match_previous_hardcoded_behavior() {
+        let config = ClientConfig::default();
+        assert_eq!(config.request_timeout, Duration::from_secs(30));
+        assert!(config.connect_timeout.is_none());
+        assert!(config.proxy.is_none());
+        assert!(config.root_cert_pem.is_none());
+        assert!(config.pool_idle_timeout.is_none());
+        assert!(config.pool_max_idle_per_host.is_none());
+        assert!(!config.disable_compression);
+    }
+
+    #[test]
+    fn invalid_root_cert_pem_is_rejected() {
+        let config = ClientConfig {
+            root_cert_pem: Some(b"not a certificate".to_vec()),
+            ..ClientConfig::default()
+        };
+        let result = RestfulLanceDbClient::try_new_with_config(
+            "db://test-db",
+            "fake-key",
+            "us-east-1",
+            None,
+            config,
+        );
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(test)]
+mod check_response_tests {
+    use super::test_utils::client_with_handler;
+    use crate::error::Error;
+
+    #[tokio::test]
+    async fn non_200_2xx_is_treated_as_success() {
+        let client = client_with_handler(|_req| {
+            http::response::Response::builder()
+                .status(202)
+                .body(String::new())
+                .unwrap()
+        });
+        let response = client.send(client.get("/v1/table/")).await.unwrap();
+        assert!(client.check_response(response).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn client_error_surfaces_request_id_and_json_message() {
+        let client = client_with_handler(|_req| {
+            http::response::Response::builder()
+                .status(404)
+                .header("x-request-id", "req-123")
+                .body(r#"{"message": "table not found"}"#.to_string())
+                .unwrap()
+        });
+        let response = client.send(client.get("/v1/table/")).await.unwrap();
+        let err = client.check_response(response).await.unwrap_err();
+        match err {
+            Error::Remote {
+                status,
+                request_id,
+                message,
+            } => {
+                assert_eq!(status, 404);
+                assert_eq!(request_id.as_deref(), Some("req-123"));
+                assert_eq!(message, "table not found");
+            }
+            other => panic!("expected Remote, got {:?}", other),
         }
     }
+
+    #[tokio::test]
+    async fn server_error_falls_back_to_raw_text_without_request_id() {
+        let client = client_with_handler(|_req| {
+            http::response::Response::builder()
+                .status(500)
+                .body("internal error".to_string())
+                .unwrap()
+        });
+        let response = client.send(client.get("/v1/table/")).await.unwrap();
+        let err = client.check_response(response).await.unwrap_err();
+        match err {
+            Error::Remote {
+                status,
+                request_id,
+                message,
+            } => {
+                assert_eq!(status, 500);
+                assert_eq!(request_id, None);
+                assert_eq!(message, "internal error");
+            }
+            other => panic!("expected Remote, got {:?}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod retry_tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::test_utils::client_with_handler;
+    use super::RetryConfig;
+
+    #[tokio::test]
+    async fn retries_on_429_then_succeeds() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let client = client_with_handler(move |_req| {
+            let attempt = calls_clone.fetch_add(1, Ordering::SeqCst);
+            if attempt == 0 {
+                http::response::Response::builder()
+                    .status(429)
+                    .body("rate limited".to_string())
+                    .unwrap()
+            } else {
+                http::response::Response::builder()
+                    .status(200)
+                    .body("ok".to_string())
+                    .unwrap()
+            }
+        })
+        .with_retry_config(RetryConfig {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(5),
+        });
+
+        let response = client.send(client.get("/v1/table/")).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let client = client_with_handler(move |_req| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            http::response::Response::builder()
+                .status(503)
+                .body("overloaded".to_string())
+                .unwrap()
+        })
+        .with_retry_config(RetryConfig {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(5),
+        });
+
+        let response = client.send(client.get("/v1/table/")).await.unwrap();
+        assert_eq!(response.status(), 503);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn retry_after_header_overrides_backoff() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let client = client_with_handler(move |_req| {
+            let attempt = calls_clone.fetch_add(1, Ordering::SeqCst);
+            if attempt == 0 {
+                http::response::Response::builder()
+                    .status(429)
+                    .header("Retry-After", "0")
+                    .body("rate limited".to_string())
+                    .unwrap()
+            } else {
+                http::response::Response::builder()
+                    .status(200)
+                    .body("ok".to_string())
+                    .unwrap()
+            }
+        })
+        .with_retry_config(RetryConfig {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_secs(30),
+            max_delay: std::time::Duration::from_secs(60),
+        });
+
+        let response = client.send(client.get("/v1/table/")).await.unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn non_idempotent_post_is_not_retried_on_503() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let client = client_with_handler(move |_req| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            http::response::Response::builder()
+                .status(503)
+                .body("overloaded".to_string())
+                .unwrap()
+        })
+        .with_retry_config(RetryConfig {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(5),
+        });
+
+        let response = client.send(client.post("/v1/table/")).await.unwrap();
+        assert_eq!(response.status(), 503);
+        // A POST isn't replayed even though the response said to retry,
+        // since the server may already have applied it once.
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
+
+#[cfg(test)]
+mod pagination_tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use futures::TryStreamExt;
+
+    use super::test_utils::client_with_handler;
+
+    #[tokio::test]
+    async fn follows_link_header_across_pages() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let requested_urls = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let requested_urls_clone = requested_urls.clone();
+        let client = client_with_handler(move |req| {
+            requested_urls_clone
+                .lock()
+                .unwrap()
+                .push(req.url().to_string());
+            let page = calls_clone.fetch_add(1, Ordering::SeqCst);
+            match page {
+                0 => http::response::Response::builder()
+                    .status(200)
+                    .header(
+                        "Link",
+                        "<http://localhost/v1/table/?cursor=1>; rel=\"next\"",
+                    )
+                    .body(r#"{"items": ["a", "b"]}"#.to_string())
+                    .unwrap(),
+                1 => http::response::Response::builder()
+                    .status(200)
+                    .body(r#"{"items": ["c"]}"#.to_string())
+                    .unwrap(),
+                _ => panic!("expected only two pages to be fetched"),
+            }
+        });
+
+        let items: Vec<String> = client
+            .list_all("/v1/table/")
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items, vec!["a", "b", "c"]);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        // The second request must hit the absolute URI from the `Link`
+        // header verbatim, not have `self.host` re-prepended onto it.
+        let urls = requested_urls.lock().unwrap();
+        assert_eq!(urls[1], "http://localhost/v1/table/?cursor=1");
+    }
+
+    #[tokio::test]
+    async fn single_page_without_link_header_stops_immediately() {
+        let client = client_with_handler(|_req| {
+            http::response::Response::builder()
+                .status(200)
+                .body(r#"{"items": [1, 2, 3]}"#.to_string())
+                .unwrap()
+        });
+
+        let items: Vec<i32> = client
+            .list_all("/v1/table/")
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items, vec![1, 2, 3]);
+    }
 }
@@ -0,0 +1,43 @@
+// Copyright 2024 Lance Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod metrics;
+pub mod table;
+
+/// Crate-local error type. Variants carry a formatted `message` rather than
+/// structured fields, matching the `Http`/`InvalidInput`/`Runtime` variants
+/// of `lancedb::error::Error` (this crate has no counterpart to its
+/// structured `Remote` variant), since this
+/// crate is being incrementally migrated onto `lancedb` rather than growing
+/// its own parallel error hierarchy.
+#[derive(Debug)]
+pub enum Error {
+    Http { message: String },
+    InvalidInput { message: String },
+    Runtime { message: String },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Http { message } => write!(f, "{}", message),
+            Error::InvalidInput { message } => write!(f, "{}", message),
+            Error::Runtime { message } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
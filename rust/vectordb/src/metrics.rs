@@ -0,0 +1,226 @@
+// Copyright 2024 Lance Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal, dependency-free metrics subsystem.
+//!
+//! LanceDB embeds into long-running applications rather than running as its
+//! own server, so metrics are exposed with a pull model: callers read
+//! [`global_registry`] and render it with [`Registry::render_prometheus`]
+//! from whatever HTTP endpoint their application already serves, rather than
+//! LanceDB pushing anywhere or binding a port itself.
+//!
+//! Counters and histogram buckets are labeled by table name and operation so
+//! a scrape can be grouped into per-table, per-operation series (e.g.
+//! `lancedb_rows_returned_total{table="docs",op="search"}`).
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::{Mutex, OnceLock};
+
+/// A single labeled counter, monotonically increasing.
+#[derive(Debug, Default)]
+struct Counter(u64);
+
+/// A simple histogram: a running count/sum plus fixed latency-style buckets.
+/// This intentionally mirrors the Prometheus client histogram shape rather
+/// than pulling in a full metrics crate.
+#[derive(Debug)]
+struct Histogram {
+    buckets: Vec<f64>,
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(buckets: &[f64]) -> Self {
+        Self {
+            buckets: buckets.to_vec(),
+            counts: vec![0; buckets.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        self.sum += value;
+        self.count += 1;
+        for (bucket, count) in self.buckets.iter().zip(self.counts.iter_mut()) {
+            if value <= *bucket {
+                *count += 1;
+            }
+        }
+    }
+}
+
+/// Default latency buckets, in seconds, used for operation-duration
+/// histograms (index builds, searches).
+const DEFAULT_LATENCY_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0, 30.0,
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Labels {
+    table: String,
+    op: &'static str,
+}
+
+/// A process-wide registry of counters and histograms.
+///
+/// Obtain the shared instance with [`global_registry`]; there is normally no
+/// reason to construct one directly.
+#[derive(Debug, Default)]
+pub struct Registry {
+    counters: Mutex<HashMap<(&'static str, Labels), Counter>>,
+    histograms: Mutex<HashMap<(&'static str, Labels), Histogram>>,
+}
+
+impl Registry {
+    fn incr(&self, name: &'static str, table: &str, op: &'static str, delta: u64) {
+        let labels = Labels {
+            table: table.to_string(),
+            op,
+        };
+        let mut counters = self.counters.lock().unwrap();
+        counters.entry((name, labels)).or_default().0 += delta;
+    }
+
+    fn observe(&self, name: &'static str, table: &str, op: &'static str, value: f64) {
+        let labels = Labels {
+            table: table.to_string(),
+            op,
+        };
+        let mut histograms = self.histograms.lock().unwrap();
+        histograms
+            .entry((name, labels))
+            .or_insert_with(|| Histogram::new(DEFAULT_LATENCY_BUCKETS))
+            .observe(value);
+    }
+
+    /// Records that a table was opened.
+    pub fn record_table_opened(&self, table: &str) {
+        self.incr("lancedb_tables_opened_total", table, "open", 1);
+    }
+
+    /// Records a vector/FTS search: how many rows were scanned by the index
+    /// and how many were ultimately returned to the caller.
+    pub fn record_search(&self, table: &str, rows_scanned: u64, rows_returned: u64, duration_secs: f64) {
+        self.incr("lancedb_rows_scanned_total", table, "search", rows_scanned);
+        self.incr("lancedb_rows_returned_total", table, "search", rows_returned);
+        self.observe("lancedb_op_duration_seconds", table, "search", duration_secs);
+    }
+
+    /// Records the outcome of a merge-insert operation.
+    pub fn record_merge_insert(&self, table: &str, rows_matched: u64, rows_inserted: u64) {
+        self.incr("lancedb_rows_matched_total", table, "merge_insert", rows_matched);
+        self.incr("lancedb_rows_inserted_total", table, "merge_insert", rows_inserted);
+    }
+
+    /// Records how long an index build took.
+    pub fn record_index_build(&self, table: &str, duration_secs: f64) {
+        self.observe("lancedb_op_duration_seconds", table, "create_index", duration_secs);
+    }
+
+    /// Records bytes read from the object store while serving `op`.
+    pub fn record_io_bytes_read(&self, table: &str, op: &'static str, bytes: u64) {
+        self.incr("lancedb_io_bytes_read_total", table, op, bytes);
+    }
+
+    /// Renders all recorded series in the [Prometheus text exposition
+    /// format][fmt].
+    ///
+    /// [fmt]: https://github.com/prometheus/docs/blob/main/content/docs/instrumenting/exposition_formats.md
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+        let counters = self.counters.lock().unwrap();
+        for ((name, labels), counter) in counters.iter() {
+            let _ = writeln!(
+                out,
+                "{}{{table=\"{}\",op=\"{}\"}} {}",
+                name, labels.table, labels.op, counter.0
+            );
+        }
+        drop(counters);
+
+        let histograms = self.histograms.lock().unwrap();
+        for ((name, labels), histogram) in histograms.iter() {
+            let mut cumulative = 0u64;
+            for (bucket, count) in histogram.buckets.iter().zip(histogram.counts.iter()) {
+                cumulative = cumulative.max(*count);
+                let _ = writeln!(
+                    out,
+                    "{}_bucket{{table=\"{}\",op=\"{}\",le=\"{}\"}} {}",
+                    name, labels.table, labels.op, bucket, cumulative
+                );
+            }
+            let _ = writeln!(
+                out,
+                "{}_bucket{{table=\"{}\",op=\"{}\",le=\"+Inf\"}} {}",
+                name, labels.table, labels.op, histogram.count
+            );
+            let _ = writeln!(
+                out,
+                "{}_sum{{table=\"{}\",op=\"{}\"}} {}",
+                name, labels.table, labels.op, histogram.sum
+            );
+            let _ = writeln!(
+                out,
+                "{}_count{{table=\"{}\",op=\"{}\"}} {}",
+                name, labels.table, labels.op, histogram.count
+            );
+        }
+        out
+    }
+}
+
+/// Returns the process-wide metrics registry, creating it on first use.
+pub fn global_registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Registry::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counters_accumulate_per_table_and_op() {
+        let registry = Registry::default();
+        registry.record_table_opened("docs");
+        registry.record_table_opened("docs");
+        registry.record_table_opened("images");
+        registry.record_search("docs", 1000, 10, 0.02);
+
+        let rendered = registry.render_prometheus();
+        assert!(rendered.contains("lancedb_tables_opened_total{table=\"docs\",op=\"open\"} 2"));
+        assert!(rendered.contains("lancedb_tables_opened_total{table=\"images\",op=\"open\"} 1"));
+        assert!(rendered.contains("lancedb_rows_scanned_total{table=\"docs\",op=\"search\"} 1000"));
+    }
+
+    #[test]
+    fn histogram_buckets_are_cumulative() {
+        let registry = Registry::default();
+        registry.observe("lancedb_op_duration_seconds", "docs", "search", 0.02);
+        registry.observe("lancedb_op_duration_seconds", "docs", "search", 2.0);
+
+        let rendered = registry.render_prometheus();
+        assert!(rendered.contains(
+            "lancedb_op_duration_seconds_bucket{table=\"docs\",op=\"search\",le=\"0.05\"} 1"
+        ));
+        assert!(rendered.contains(
+            "lancedb_op_duration_seconds_bucket{table=\"docs\",op=\"search\",le=\"5\"} 2"
+        ));
+        assert!(rendered.contains("lancedb_op_duration_seconds_count{table=\"docs\",op=\"search\"} 2"));
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2024 Lance Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatchReader;
+use async_trait::async_trait;
+
+use super::merge::MergeInsertBuilder;
+use crate::Result;
+
+/// A single operation accumulated by a [`BatchBuilder`].
+///
+/// Each variant mirrors a method already available directly on [`super::Table`]
+/// (`add`, `delete`, `merge_insert`), but here the data/predicate is captured
+/// instead of executed immediately.
+pub enum BatchOp {
+    Add(Box<dyn RecordBatchReader + Send>),
+    Delete(String),
+    MergeInsert {
+        builder: MergeInsertBuilder,
+        new_data: Box<dyn RecordBatchReader + Send>,
+    },
+}
+
+impl std::fmt::Debug for BatchOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatchOp::Add(_) => write!(f, "Add(..)"),
+            BatchOp::Delete(predicate) => write!(f, "Delete({:?})", predicate),
+            BatchOp::MergeInsert { .. } => write!(f, "MergeInsert {{ .. }}"),
+        }
+    }
+}
+
+#[async_trait]
+pub(super) trait Batch: Send + Sync {
+    async fn do_batch(&self, ops: Vec<BatchOp>) -> Result<()>;
+}
+
+/// A builder used to group several `add`/`delete`/`merge_insert` operations
+/// against one [`super::Table`] and commit them as a single new dataset
+/// version.
+///
+/// The key invariant is all-or-nothing: [`Self::execute`] hands every
+/// accumulated op to [`super::DatasetWriter::commit_batch`] in one call, and
+/// none of them become visible until that call succeeds — either every op
+/// lands in the resulting version, or (on any failure, including a detected
+/// concurrent commit) none do and the table is left at its prior version.
+/// This is what distinguishes a batch from calling `add`/`delete`/
+/// `merge_insert` once per op: those each commit their own version
+/// immediately, so a failure partway through leaves some ops applied and
+/// others not.
+///
+/// See [`super::Table::new_batch`] for more context.
+pub struct BatchBuilder {
+    table: Arc<dyn Batch>,
+    ops: Vec<BatchOp>,
+}
+
+impl BatchBuilder {
+    pub(super) fn new(table: Arc<dyn Batch>) -> Self {
+        Self {
+            table,
+            ops: Vec::new(),
+        }
+    }
+
+    /// Queues an `add` of `new_data` as part of this batch.
+    pub fn add(mut self, new_data: Box<dyn RecordBatchReader + Send>) -> Self {
+        self.ops.push(BatchOp::Add(new_data));
+        self
+    }
+
+    /// Queues a `delete` matching `predicate` as part of this batch.
+    pub fn delete(mut self, predicate: impl Into<String>) -> Self {
+        self.ops.push(BatchOp::Delete(predicate.into()));
+        self
+    }
+
+    /// Queues a `merge_insert` as part of this batch. `builder` should be
+    /// configured (e.g. via `when_matched_update_all`) but not yet executed.
+    pub fn merge_insert(
+        mut self,
+        builder: MergeInsertBuilder,
+        new_data: Box<dyn RecordBatchReader + Send>,
+    ) -> Self {
+        self.ops.push(BatchOp::MergeInsert { builder, new_data });
+        self
+    }
+
+    /// Returns the number of operations queued so far.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Returns true if no operations have been queued.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Commits all queued operations as a single new dataset version. See
+    /// the type-level docs for the all-or-nothing guarantee this provides.
+    pub async fn execute(self) -> Result<()> {
+        self.table.do_batch(self.ops).await
+    }
+}
@@ -35,6 +35,8 @@ pub struct MergeInsertBuilder {
     table: Arc<dyn MergeInsert>,
     pub(super) on: Vec<String>,
     pub(super) when_matched_update_all: bool,
+    pub(super) when_matched_update_all_filt: Option<String>,
+    pub(super) when_matched_update_all_columns: Option<Vec<String>>,
     pub(super) when_not_matched_insert_all: bool,
     pub(super) when_not_matched_by_source_delete: bool,
     pub(super) when_not_matched_by_source_delete_filt: Option<String>,
@@ -46,6 +48,8 @@ impl MergeInsertBuilder {
             table,
             on,
             when_matched_update_all: false,
+            when_matched_update_all_filt: None,
+            when_matched_update_all_columns: None,
             when_not_matched_insert_all: false,
             when_not_matched_by_source_delete: false,
             when_not_matched_by_source_delete_filt: None,
@@ -64,6 +68,32 @@ impl MergeInsertBuilder {
         self
     }
 
+    /// Rows that exist in both the source table (new data) and the target
+    /// table (old data) will be updated, subject to an optional predicate
+    /// and an optional explicit set of columns to overwrite.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - If `Some`, an SQL expression evaluated over the
+    ///   matched source/target columns (e.g. `"source.version > target.version"`).
+    ///   Only rows for which this predicate holds are updated; unlike
+    ///   [`Self::when_matched_update_all`] this lets callers guard an upsert
+    ///   so a stale incoming row does not clobber a newer one.
+    /// * `columns` - If `Some`, only these columns are overwritten with the
+    ///   incoming values; all other columns in the target row are left
+    ///   intact. If `None`, the entire row is replaced, as with
+    ///   [`Self::when_matched_update_all`].
+    pub fn when_matched_update(
+        &mut self,
+        condition: Option<String>,
+        columns: Option<Vec<String>>,
+    ) -> &mut Self {
+        self.when_matched_update_all = true;
+        self.when_matched_update_all_filt = condition;
+        self.when_matched_update_all_columns = columns;
+        self
+    }
+
     /// Rows that exist only in the source table (new data) should
     /// be inserted into the target table.
     pub fn when_not_matched_insert_all(&mut self) -> &mut Self {
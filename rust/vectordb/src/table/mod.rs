@@ -0,0 +1,368 @@
+// Copyright 2024 Lance Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod batch;
+pub mod merge;
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatchReader;
+use async_trait::async_trait;
+use chrono::Duration;
+use lance::dataset::cleanup::RemovalStats;
+use lance::dataset::optimize::{CompactionMetrics, CompactionOptions};
+use lance::dataset::WriteParams;
+
+pub use batch::{Batch, BatchBuilder, BatchOp};
+pub use merge::{MergeInsert, MergeInsertBuilder};
+
+use crate::{Error, Result};
+
+/// The underlying dataset write operations a [`Table`] delegates to.
+///
+/// This mirrors `lancedb::remote::client::HttpSend`: [`Table`] only talks
+/// to this trait, so tests can substitute an in-memory fake and exercise
+/// [`BatchBuilder`]/[`MergeInsertBuilder`] end-to-end without touching
+/// storage. This crate contains no implementation backed by a Lance
+/// dataset; the in-memory fake in the tests is the only implementation.
+#[async_trait]
+pub(crate) trait DatasetWriter: std::fmt::Debug + Send + Sync {
+    async fn append(
+        &self,
+        new_data: Box<dyn RecordBatchReader + Send>,
+        params: Option<WriteParams>,
+    ) -> Result<()>;
+    async fn delete(&self, predicate: &str) -> Result<()>;
+    async fn merge_insert(
+        &self,
+        params: &MergeInsertBuilder,
+        new_data: Box<dyn RecordBatchReader + Send>,
+    ) -> Result<()>;
+    async fn count_rows(&self) -> Result<usize>;
+    async fn cleanup_old_versions(
+        &self,
+        older_than: Duration,
+        delete_unverified: Option<bool>,
+    ) -> Result<RemovalStats>;
+    async fn compact_files(&self, options: CompactionOptions) -> Result<CompactionMetrics>;
+
+    /// Commits every op in `ops` as a single new dataset version: either all
+    /// of them land, or (on any failure, including a detected concurrent
+    /// commit) none do and the table is left at its prior version. This is
+    /// what makes [`BatchBuilder::execute`] atomic rather than equivalent to
+    /// calling `append`/`delete`/`merge_insert` once per op.
+    async fn commit_batch(&self, ops: Vec<BatchOp>) -> Result<()>;
+}
+
+/// A handle to a single table's dataset, and the entry point for the
+/// operation builders in [`merge`] and [`batch`].
+///
+/// Cheaply [`Clone`]-able: the underlying dataset handle is reference
+/// counted, so every clone observes the same committed versions.
+#[derive(Clone)]
+pub struct Table {
+    name: String,
+    dataset: Arc<dyn DatasetWriter>,
+}
+
+impl std::fmt::Debug for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Table").field("name", &self.name).finish()
+    }
+}
+
+impl Table {
+    pub(crate) fn new(name: impl Into<String>, dataset: Arc<dyn DatasetWriter>) -> Self {
+        Self {
+            name: name.into(),
+            dataset,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Adds `new_data` to the table as its own committed version, using
+    /// `params` to control the write (falling back to the dataset's
+    /// existing write settings when `None`).
+    pub async fn add(
+        &mut self,
+        new_data: Box<dyn RecordBatchReader + Send>,
+        params: Option<WriteParams>,
+    ) -> Result<()> {
+        self.dataset.append(new_data, params).await
+    }
+
+    /// Deletes rows matching `predicate` as its own committed version.
+    pub async fn delete(&mut self, predicate: impl Into<String>) -> Result<()> {
+        self.dataset.delete(&predicate.into()).await
+    }
+
+    /// Returns the number of rows currently in the table.
+    pub async fn count_rows(&self) -> Result<usize> {
+        self.dataset.count_rows().await
+    }
+
+    /// Removes dataset versions (and the data files only they reference)
+    /// older than `older_than`. `delete_unverified` controls whether
+    /// versions that can't be confirmed unreferenced by any other process
+    /// are removed anyway; see the underlying Lance dataset for the exact
+    /// semantics.
+    pub async fn cleanup_old_versions(
+        &self,
+        older_than: Duration,
+        delete_unverified: Option<bool>,
+    ) -> Result<RemovalStats> {
+        self.dataset
+            .cleanup_old_versions(older_than, delete_unverified)
+            .await
+    }
+
+    /// Compacts the table's data files according to `options`, as its own
+    /// committed version.
+    pub async fn compact_files(&mut self, options: CompactionOptions) -> Result<CompactionMetrics> {
+        self.dataset.compact_files(options).await
+    }
+
+    /// Starts a merge-insert operation against `on`. See
+    /// [`MergeInsertBuilder`] for the available match behaviors.
+    pub fn merge_insert(&self, on: Vec<String>) -> MergeInsertBuilder {
+        MergeInsertBuilder::new(Arc::new(self.clone()), on)
+    }
+
+    /// Starts a [`BatchBuilder`] that groups several `add`/`delete`/
+    /// `merge_insert` operations against this table into one committed
+    /// version. See [`BatchBuilder::execute`] for the atomicity contract.
+    pub fn new_batch(&self) -> BatchBuilder {
+        BatchBuilder::new(Arc::new(self.clone()))
+    }
+}
+
+#[async_trait]
+impl MergeInsert for Table {
+    async fn do_merge_insert(
+        &self,
+        params: MergeInsertBuilder,
+        new_data: Box<dyn RecordBatchReader + Send>,
+    ) -> Result<()> {
+        self.dataset.merge_insert(&params, new_data).await
+    }
+}
+
+#[async_trait]
+impl Batch for Table {
+    async fn do_batch(&self, ops: Vec<BatchOp>) -> Result<()> {
+        self.dataset.commit_batch(ops).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use arrow_array::{Int64Array, RecordBatch, RecordBatchIterator};
+    use arrow_schema::{DataType, Field, Schema};
+
+    use super::*;
+
+    /// An in-memory [`DatasetWriter`] double: each op appends to a flat
+    /// history instead of committing a real Lance dataset version, which is
+    /// enough to prove `BatchBuilder`/`MergeInsertBuilder` actually reach a
+    /// concrete `Table` and run the ops they were given, in order.
+    ///
+    /// `fail_op_at`, if set, makes [`commit_batch`](DatasetWriter::commit_batch)
+    /// fail while staging the op at that index, so tests can assert that a
+    /// failed batch leaves `applied` untouched instead of partially updated.
+    #[derive(Debug, Default)]
+    struct FakeDataset {
+        applied: Mutex<Vec<String>>,
+        fail_op_at: Mutex<Option<usize>>,
+    }
+
+    #[async_trait]
+    impl DatasetWriter for FakeDataset {
+        async fn append(
+            &self,
+            new_data: Box<dyn RecordBatchReader + Send>,
+            _params: Option<WriteParams>,
+        ) -> Result<()> {
+            let rows: usize = new_data.map(|b| b.unwrap().num_rows()).sum();
+            self.applied.lock().unwrap().push(format!("add({})", rows));
+            Ok(())
+        }
+
+        async fn delete(&self, predicate: &str) -> Result<()> {
+            self.applied
+                .lock()
+                .unwrap()
+                .push(format!("delete({})", predicate));
+            Ok(())
+        }
+
+        async fn merge_insert(
+            &self,
+            _params: &MergeInsertBuilder,
+            new_data: Box<dyn RecordBatchReader + Send>,
+        ) -> Result<()> {
+            let rows: usize = new_data.map(|b| b.unwrap().num_rows()).sum();
+            self.applied
+                .lock()
+                .unwrap()
+                .push(format!("merge_insert({})", rows));
+            Ok(())
+        }
+
+        async fn count_rows(&self) -> Result<usize> {
+            let applied = self.applied.lock().unwrap();
+            Ok(applied
+                .iter()
+                .filter_map(|entry| {
+                    entry
+                        .strip_prefix("add(")
+                        .or_else(|| entry.strip_prefix("merge_insert("))
+                        .and_then(|rest| rest.strip_suffix(')'))
+                        .and_then(|n| n.parse::<usize>().ok())
+                })
+                .sum())
+        }
+
+        async fn cleanup_old_versions(
+            &self,
+            _older_than: Duration,
+            _delete_unverified: Option<bool>,
+        ) -> Result<RemovalStats> {
+            Ok(RemovalStats::default())
+        }
+
+        async fn compact_files(&self, _options: CompactionOptions) -> Result<CompactionMetrics> {
+            Ok(CompactionMetrics::default())
+        }
+
+        async fn commit_batch(&self, ops: Vec<BatchOp>) -> Result<()> {
+            let mut staged = Vec::with_capacity(ops.len());
+            for (i, op) in ops.into_iter().enumerate() {
+                if *self.fail_op_at.lock().unwrap() == Some(i) {
+                    return Err(Error::Runtime {
+                        message: format!("simulated failure committing batch op {}", i),
+                    });
+                }
+                staged.push(match op {
+                    BatchOp::Add(new_data) => {
+                        let rows: usize = new_data.map(|b| b.unwrap().num_rows()).sum();
+                        format!("add({})", rows)
+                    }
+                    BatchOp::Delete(predicate) => format!("delete({})", predicate),
+                    BatchOp::MergeInsert { new_data, .. } => {
+                        let rows: usize = new_data.map(|b| b.unwrap().num_rows()).sum();
+                        format!("merge_insert({})", rows)
+                    }
+                });
+            }
+            // Only now, after every op staged cleanly, do any of them become
+            // visible — this is the all-or-nothing half of the atomicity
+            // contract.
+            self.applied.lock().unwrap().extend(staged);
+            Ok(())
+        }
+    }
+
+    fn sample_batch(num_rows: i64) -> Box<dyn RecordBatchReader + Send> {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from_iter_values(0..num_rows))],
+        )
+        .unwrap();
+        Box::new(RecordBatchIterator::new(vec![Ok(batch)], schema))
+    }
+
+    #[tokio::test]
+    async fn new_batch_executes_ops_in_order_against_the_table() {
+        let dataset = Arc::new(FakeDataset::default());
+        let table = Table::new("my_table", dataset.clone());
+
+        table
+            .new_batch()
+            .add(sample_batch(3))
+            .delete("id > 100")
+            .merge_insert(
+                {
+                    let mut b = table.merge_insert(vec!["id".to_string()]);
+                    b.when_matched_update_all();
+                    b
+                },
+                sample_batch(2),
+            )
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *dataset.applied.lock().unwrap(),
+            vec![
+                "add(3)".to_string(),
+                "delete(id > 100)".to_string(),
+                "merge_insert(2)".to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn merge_insert_builder_reaches_the_table() {
+        let dataset = Arc::new(FakeDataset::default());
+        let table = Table::new("my_table", dataset.clone());
+
+        let mut builder = table.merge_insert(vec!["id".to_string()]);
+        builder.when_not_matched_insert_all();
+        builder.execute(sample_batch(5)).await.unwrap();
+
+        assert_eq!(
+            *dataset.applied.lock().unwrap(),
+            vec!["merge_insert(5)".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_batch_is_a_no_op() {
+        let dataset = Arc::new(FakeDataset::default());
+        let table = Table::new("my_table", dataset.clone());
+
+        let batch = table.new_batch();
+        assert!(batch.is_empty());
+        batch.execute().await.unwrap();
+
+        assert!(dataset.applied.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_batch_leaves_no_partial_commit() {
+        let dataset = Arc::new(FakeDataset::default());
+        let table = Table::new("my_table", dataset.clone());
+        // The second op (the delete) fails while staging; the add before it
+        // must not survive into `applied` on its own.
+        *dataset.fail_op_at.lock().unwrap() = Some(1);
+
+        let err = table
+            .new_batch()
+            .add(sample_batch(3))
+            .delete("id > 100")
+            .execute()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, Error::Runtime { .. }));
+        assert!(dataset.applied.lock().unwrap().is_empty());
+    }
+}